@@ -22,4 +22,60 @@ pub enum StakingError {
     InvalidLpFeeDistribution,
     #[msg("Math overflow")]
     MathOverflow,
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[msg("xLEASH mint must be empty and controlled by the vault authority")]
+    InvalidXleashMint,
+    #[msg("Insufficient funds in the reward vault")]
+    InsufficientRewardFunds,
+    #[msg("Stake vault balance does not cover total staked")]
+    VaultInvariantViolated,
+    #[msg("Invalid lock tier")]
+    InvalidLockTier,
+    #[msg("Invalid unbonding period")]
+    InvalidUnbondingPeriod,
+    #[msg("Too many pending unbonding requests")]
+    TooManyUnbondingRequests,
+    #[msg("Unbonding request not found")]
+    InvalidUnbondingRequest,
+    #[msg("Unbonding cooldown has not elapsed")]
+    UnbondingNotComplete,
+    #[msg("Invalid early-exit penalty parameters")]
+    InvalidPenaltyParams,
+    #[msg("Keeper fee exceeds the maximum")]
+    InvalidKeeperFee,
+    #[msg("Crank accounts must be position and xLEASH account pairs")]
+    InvalidCrankAccounts,
+    #[msg("Invalid reward stream")]
+    InvalidRewardStream,
+    #[msg("All reward stream slots are in use")]
+    RewardStreamLimitReached,
+    #[msg("Invalid reward period duration")]
+    InvalidRewardDuration,
+    #[msg("Invalid emission schedule")]
+    InvalidEmissionSchedule,
+    #[msg("Reward rate is set by the emission schedule")]
+    EmissionScheduleActive,
+    #[msg("Invalid parameter timelock")]
+    InvalidParamTimelock,
+    #[msg("Parameter changes must be queued while the timelock is enabled")]
+    ParamChangeTimelocked,
+    #[msg("Parameter change timelock has not elapsed")]
+    ParamChangeNotReady,
+    #[msg("Invalid multisig members or threshold")]
+    InvalidMultisig,
+    #[msg("Signer is not a multisig member")]
+    NotMultisigMember,
+    #[msg("Member has already approved this proposal")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalNotApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("No reward period is running")]
+    RewardPeriodInactive,
+    #[msg("Proposal predates a change to the multisig members")]
+    StaleMultisigProposal,
+    #[msg("Accounts do not match the multisig action")]
+    InvalidMultisigAccounts,
 }
@@ -0,0 +1,766 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{LockTier, MultisigAction, ParamChange, MAX_LOCK_TIERS};
+
+/// Emitted when staking parameters are changed
+#[event]
+pub struct ParamsUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Authority that made the change
+    pub authority: Pubkey,
+    
+    /// Minimum stake amount before and after the update
+    pub old_min_stake_amount: u64,
+    pub new_min_stake_amount: u64,
+    
+    /// Maximum stake amount before and after the update
+    pub old_max_stake_amount: u64,
+    pub new_max_stake_amount: u64,
+    
+    /// Lock period before and after the update
+    pub old_lock_period: i64,
+    pub new_lock_period: i64,
+    
+    /// Unbonding cooldown before and after the update
+    pub old_unbonding_period: i64,
+    pub new_unbonding_period: i64,
+    
+    /// Parameter change timelock before and after the update
+    pub old_param_timelock: i64,
+    pub new_param_timelock: i64,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when operations are paused or resumed
+#[event]
+pub struct PauseToggled {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Authority or guardian that toggled the flags
+    pub signer: Pubkey,
+    
+    /// Operations affected by this toggle (see `PAUSE_*`)
+    pub operations: u8,
+    
+    /// Whether the operations were paused or resumed
+    pub paused: bool,
+    
+    /// Pause flags after the toggle
+    pub pause_flags: u8,
+    
+    /// Off-chain incident reason code
+    pub reason_code: u16,
+    
+    /// Time of the toggle
+    pub timestamp: i64,
+}
+
+/// Emitted when the guardian key is changed
+#[event]
+pub struct GuardianUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Guardian before and after the update
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the lock tiers are changed
+#[event]
+pub struct LockTiersUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Authority that made the change
+    pub authority: Pubkey,
+    
+    /// Lock tiers before and after the update
+    pub old_lock_tiers: [LockTier; MAX_LOCK_TIERS],
+    pub new_lock_tiers: [LockTier; MAX_LOCK_TIERS],
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the early-exit penalty parameters are changed
+#[event]
+pub struct PenaltyParamsUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Authority that made the change
+    pub authority: Pubkey,
+    
+    /// Maximum early-exit penalty before and after the update
+    pub old_early_exit_penalty_bps: u16,
+    pub new_early_exit_penalty_bps: u16,
+    
+    /// Stakers' share of penalties before and after the update
+    pub old_penalty_staker_share_bps: u16,
+    pub new_penalty_staker_share_bps: u16,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the keeper fee is changed
+#[event]
+pub struct KeeperFeeUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Keeper fee before and after the update
+    pub old_keeper_fee_bps: u16,
+    pub new_keeper_fee_bps: u16,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when a reward stream is added
+#[event]
+pub struct RewardStreamAdded {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Slot the stream was added to
+    pub index: u8,
+    
+    /// Reward token mint and the vault holding it
+    pub reward_mint: Pubkey,
+    pub stream_vault: Pubkey,
+    
+    /// Initial emission rate
+    pub reward_rate: u64,
+    
+    /// Time the stream was added
+    pub timestamp: i64,
+}
+
+/// Emitted when a new authority is proposed
+#[event]
+pub struct AuthorityProposed {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Current authority
+    pub authority: Pubkey,
+    
+    /// Proposed authority (default key when a proposal is cancelled)
+    pub pending_authority: Pubkey,
+    
+    /// Time of the proposal
+    pub timestamp: i64,
+}
+
+/// Emitted when the pending authority accepts
+#[event]
+pub struct AuthorityTransferred {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Authority before and after the transfer
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    
+    /// Time of the transfer
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority is renounced
+#[event]
+pub struct AuthorityRenounced {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Authority that gave up control
+    pub old_authority: Pubkey,
+    
+    /// Guardian that can still toggle pause flags
+    pub guardian: Pubkey,
+    
+    /// Time of the renouncement
+    pub timestamp: i64,
+}
+
+/// Emitted when the fee distributor is changed
+#[event]
+pub struct FeeDistributorUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Fee distributor before and after the update
+    pub old_fee_distributor: Pubkey,
+    pub new_fee_distributor: Pubkey,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the rate manager or its bounds are changed
+#[event]
+pub struct RateManagerUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Rate manager before and after the update
+    pub old_rate_manager: Pubkey,
+    pub new_rate_manager: Pubkey,
+    
+    /// Bounds the rate manager may set the reward rate within
+    pub min_reward_rate: u64,
+    pub max_reward_rate: u64,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the rate manager changes the reward rate
+#[event]
+pub struct RewardRateUpdated {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Rate manager that made the change
+    pub rate_manager: Pubkey,
+    
+    /// Reward rate before and after the update
+    pub old_reward_rate: u64,
+    pub new_reward_rate: u64,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when a parameter change is queued behind the timelock
+#[event]
+pub struct ParamChangeQueued {
+    /// Global staking state the change applies to
+    pub global_staking_state: Pubkey,
+    
+    /// Sequence number of the queued change
+    pub id: u64,
+    
+    /// Authority that queued the change
+    pub proposer: Pubkey,
+    
+    /// Parameters to update
+    pub change: ParamChange,
+    
+    /// Earliest time the change can be executed
+    pub eta: i64,
+    
+    /// Time the change was queued
+    pub timestamp: i64,
+}
+
+/// Emitted when a queued parameter change is executed
+#[event]
+pub struct ParamChangeExecuted {
+    /// Global staking state that was updated
+    pub global_staking_state: Pubkey,
+    
+    /// Sequence number of the executed change
+    pub id: u64,
+    
+    /// Time of execution
+    pub timestamp: i64,
+}
+
+/// Emitted when a queued parameter change is cancelled
+#[event]
+pub struct ParamChangeCancelled {
+    /// Global staking state the change applied to
+    pub global_staking_state: Pubkey,
+    
+    /// Sequence number of the cancelled change
+    pub id: u64,
+    
+    /// Authority or guardian that cancelled it
+    pub signer: Pubkey,
+    
+    /// Time of the cancellation
+    pub timestamp: i64,
+}
+
+/// Emitted when a pool's multisig is created
+#[event]
+pub struct MultisigCreated {
+    /// Global staking state the multisig administers
+    pub global_staking_state: Pubkey,
+    
+    /// Multisig address
+    pub multisig: Pubkey,
+    
+    /// Members and the approvals required
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    
+    /// Time of creation
+    pub timestamp: i64,
+}
+
+/// Emitted when a multisig member creates a proposal
+#[event]
+pub struct MultisigProposalCreated {
+    /// Multisig the proposal belongs to
+    pub multisig: Pubkey,
+    
+    /// Sequence number of the proposal
+    pub id: u64,
+    
+    /// Member that created it
+    pub proposer: Pubkey,
+    
+    /// Action to perform once approved
+    pub action: MultisigAction,
+    
+    /// Time of creation
+    pub timestamp: i64,
+}
+
+/// Emitted when a multisig member approves a proposal
+#[event]
+pub struct MultisigProposalApproved {
+    /// Multisig the proposal belongs to
+    pub multisig: Pubkey,
+    
+    /// Sequence number of the proposal
+    pub id: u64,
+    
+    /// Member that approved
+    pub member: Pubkey,
+    
+    /// Approvals so far and the number required
+    pub approvals: u8,
+    pub threshold: u8,
+    
+    /// Time of the approval
+    pub timestamp: i64,
+}
+
+/// Emitted when an approved multisig proposal is executed
+#[event]
+pub struct MultisigProposalExecuted {
+    /// Multisig the proposal belongs to
+    pub multisig: Pubkey,
+    
+    /// Sequence number of the proposal
+    pub id: u64,
+    
+    /// Time of execution
+    pub timestamp: i64,
+}
+
+/// Emitted when an executed proposal changes the multisig's members or threshold
+#[event]
+pub struct MultisigMembersUpdated {
+    /// Multisig that changed
+    pub multisig: Pubkey,
+    
+    /// Members and the approvals required after the change
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    
+    /// New member set version; proposals from earlier versions can no longer execute
+    pub member_set_version: u32,
+    
+    /// Time of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a staking pool is created
+#[event]
+pub struct PoolInitialized {
+    /// Global staking state that was created
+    pub global_staking_state: Pubkey,
+    
+    /// Pool identifier and the mint it stakes
+    pub pool_id: u64,
+    pub leash_mint: Pubkey,
+    
+    /// Receipt token minted to stakers
+    pub xleash_mint: Pubkey,
+    
+    /// Authority that can update staking parameters
+    pub authority: Pubkey,
+    
+    /// Initial staking parameters
+    pub reward_rate: u64,
+    pub min_stake_amount: u64,
+    pub max_stake_amount: u64,
+    pub lock_period: i64,
+    
+    /// Number of emission schedule breakpoints (zero when rewards use reward periods)
+    pub emission_breakpoints: u8,
+    
+    /// Time of creation
+    pub timestamp: i64,
+}
+
+/// Emitted when a user stakes
+#[event]
+pub struct Staked {
+    /// Staker
+    pub user: Pubkey,
+    
+    /// Global staking state staked into
+    pub global_staking_state: Pubkey,
+    
+    /// LEASH deposited and xLEASH minted for it
+    pub amount: u64,
+    pub xleash_amount: u64,
+    
+    /// Lock applied to the deposit and its reward weight
+    pub lock_period: i64,
+    pub multiplier_bps: u16,
+    
+    /// User's staked amount after the deposit
+    pub user_staked_amount: u64,
+    
+    /// Pool totals after the deposit
+    pub total_staked: u64,
+    pub total_weighted_stake: u64,
+    
+    /// Time of the stake
+    pub timestamp: i64,
+}
+
+/// Emitted when a user burns xLEASH to start unbonding, with or without an early-exit penalty
+#[event]
+pub struct UnstakeRequested {
+    /// Staker
+    pub user: Pubkey,
+    
+    /// Global staking state unstaked from
+    pub global_staking_state: Pubkey,
+    
+    /// xLEASH burned
+    pub xleash_amount: u64,
+    
+    /// LEASH queued for withdrawal, after any penalty
+    pub amount: u64,
+    
+    /// Early-exit penalty deducted (zero for a regular unstake)
+    pub penalty: u64,
+    
+    /// Time the queued LEASH becomes withdrawable
+    pub unlock_time: i64,
+    
+    /// User's staked amount after the request
+    pub user_staked_amount: u64,
+    
+    /// Pool totals after the request
+    pub total_staked: u64,
+    pub total_unbonding: u64,
+    
+    /// Time of the request
+    pub timestamp: i64,
+}
+
+/// Emitted when an early-exit penalty is split between stakers and the treasury
+#[event]
+pub struct EarlyExitPenaltyPaid {
+    /// Staker that paid the penalty
+    pub user: Pubkey,
+    
+    /// Global staking state the penalty was paid to
+    pub global_staking_state: Pubkey,
+    
+    /// Total penalty and how it was split
+    pub penalty: u64,
+    pub staker_share: u64,
+    pub treasury_share: u64,
+    
+    /// Time of the payment
+    pub timestamp: i64,
+}
+
+/// Emitted when a user withdraws unbonded LEASH
+#[event]
+pub struct Unstaked {
+    /// Staker
+    pub user: Pubkey,
+    
+    /// Global staking state withdrawn from
+    pub global_staking_state: Pubkey,
+    
+    /// LEASH withdrawn
+    pub amount: u64,
+    
+    /// Pool totals after the withdrawal
+    pub total_staked: u64,
+    pub total_unbonding: u64,
+    
+    /// Time of the withdrawal
+    pub timestamp: i64,
+}
+
+/// Emitted when a user re-stakes an unbonding request
+#[event]
+pub struct UnstakeCancelled {
+    /// Staker
+    pub user: Pubkey,
+    
+    /// Global staking state re-staked into
+    pub global_staking_state: Pubkey,
+    
+    /// LEASH re-staked and xLEASH minted for it
+    pub amount: u64,
+    pub xleash_amount: u64,
+    
+    /// User's staked amount after the cancellation
+    pub user_staked_amount: u64,
+    
+    /// Pool totals after the cancellation
+    pub total_staked: u64,
+    pub total_unbonding: u64,
+    
+    /// Time of the cancellation
+    pub timestamp: i64,
+}
+
+/// Emitted when a user claims LEASH rewards
+#[event]
+pub struct RewardsClaimed {
+    /// Staker
+    pub user: Pubkey,
+    
+    /// Global staking state claimed from
+    pub global_staking_state: Pubkey,
+    
+    /// LEASH paid out
+    pub amount: u64,
+    
+    /// Pool total after the claim
+    pub total_rewards_distributed: u64,
+    
+    /// Time of the claim
+    pub timestamp: i64,
+}
+
+/// Emitted for each reward stream a user claims from
+#[event]
+pub struct StreamRewardsClaimed {
+    /// Staker
+    pub user: Pubkey,
+    
+    /// Global staking state claimed from
+    pub global_staking_state: Pubkey,
+    
+    /// Stream slot and its reward token
+    pub index: u8,
+    pub reward_mint: Pubkey,
+    
+    /// Reward tokens paid out
+    pub amount: u64,
+    
+    /// Stream total after the claim
+    pub total_distributed: u64,
+    
+    /// Time of the claim
+    pub timestamp: i64,
+}
+
+/// Emitted when a position's rewards are restaked, by its owner or a keeper
+#[event]
+pub struct RewardsCompounded {
+    /// Position owner
+    pub user: Pubkey,
+    
+    /// Global staking state compounded into
+    pub global_staking_state: Pubkey,
+    
+    /// Owner or keeper that triggered the compound
+    pub caller: Pubkey,
+    
+    /// LEASH restaked and xLEASH minted for it
+    pub amount: u64,
+    pub xleash_amount: u64,
+    
+    /// LEASH paid to the keeper out of the rewards
+    pub keeper_fee: u64,
+    
+    /// User's staked amount after the compound
+    pub user_staked_amount: u64,
+    
+    /// Pool total after the compound
+    pub total_staked: u64,
+    
+    /// Time of the compound
+    pub timestamp: i64,
+}
+
+/// Emitted when LP fees are distributed as staking rewards
+#[event]
+pub struct LpFeesDistributed {
+    /// Global staking state the fees were paid to
+    pub global_staking_state: Pubkey,
+    
+    /// Authority or fee distributor that paid them in
+    pub distributor: Pubkey,
+    
+    /// LEASH distributed
+    pub amount: u64,
+    
+    /// Pool totals after the distribution
+    pub lp_fees_collected: u64,
+    pub undistributed_lp_fees: u64,
+    pub accumulated_rewards_per_token: u128,
+    pub total_weighted_stake: u64,
+    
+    /// Time of the distribution
+    pub timestamp: i64,
+}
+
+/// Emitted when LP fees are compounded into the pool
+#[event]
+pub struct LpFeesCompounded {
+    /// Global staking state the fees were added to
+    pub global_staking_state: Pubkey,
+    
+    /// Authority or fee distributor that paid them in
+    pub distributor: Pubkey,
+    
+    /// LEASH compounded
+    pub amount: u64,
+    
+    /// xLEASH exchange rate after the compound, scaled by `EXCHANGE_RATE_PRECISION`
+    pub exchange_rate: u64,
+    
+    /// Pool total after the compound
+    pub total_staked: u64,
+    
+    /// Time of the compound
+    pub timestamp: i64,
+}
+
+/// Emitted when the reward vault is topped up
+#[event]
+pub struct RewardsFunded {
+    /// Global staking state funded
+    pub global_staking_state: Pubkey,
+    
+    /// Account that paid in the LEASH
+    pub funder: Pubkey,
+    
+    /// LEASH deposited
+    pub amount: u64,
+    
+    /// Pool total after the deposit
+    pub total_rewards_funded: u64,
+    
+    /// Time of the deposit
+    pub timestamp: i64,
+}
+
+/// Emitted when a reward period starts
+#[event]
+pub struct RewardPeriodStarted {
+    /// Global staking state the period applies to
+    pub global_staking_state: Pubkey,
+    
+    /// Authority that started the period
+    pub authority: Pubkey,
+    
+    /// LEASH added and the period length
+    pub amount: u64,
+    pub duration: i64,
+    
+    /// Emission rate and end of the new period
+    pub reward_rate: u64,
+    pub period_finish: i64,
+    
+    /// Pool total after the deposit
+    pub total_rewards_funded: u64,
+    
+    /// Time the period started
+    pub timestamp: i64,
+}
+
+/// Emitted when a reward stream's vault is topped up
+#[event]
+pub struct RewardStreamFunded {
+    /// Global staking state the stream belongs to
+    pub global_staking_state: Pubkey,
+    
+    /// Stream slot
+    pub index: u8,
+    
+    /// Account that paid in the tokens
+    pub funder: Pubkey,
+    
+    /// Reward tokens deposited
+    pub amount: u64,
+    
+    /// Stream total after the deposit
+    pub total_funded: u64,
+    
+    /// Time of the deposit
+    pub timestamp: i64,
+}
+
+/// Emitted when fees are distributed through a reward stream
+#[event]
+pub struct StreamFeesDistributed {
+    /// Global staking state the stream belongs to
+    pub global_staking_state: Pubkey,
+    
+    /// Stream slot
+    pub index: u8,
+    
+    /// Authority or fee distributor that paid in the fees
+    pub distributor: Pubkey,
+    
+    /// Reward tokens distributed
+    pub amount: u64,
+    
+    /// Stream totals after the distribution
+    pub accumulated_rewards_per_token: u128,
+    pub total_funded: u64,
+    
+    /// Time of the distribution
+    pub timestamp: i64,
+}
+
+/// Emitted when a reward stream's emission rate is changed
+#[event]
+pub struct RewardStreamRateUpdated {
+    /// Global staking state the stream belongs to
+    pub global_staking_state: Pubkey,
+    
+    /// Stream slot
+    pub index: u8,
+    
+    /// Emission rate before and after the update
+    pub old_reward_rate: u64,
+    pub new_reward_rate: u64,
+    
+    /// Time of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when a user opts in or out of auto-compounding
+#[event]
+pub struct AutoCompoundToggled {
+    /// Position owner
+    pub user: Pubkey,
+    
+    /// Global staking state the position belongs to
+    pub global_staking_state: Pubkey,
+    
+    /// Whether keepers may now compound the position
+    pub enabled: bool,
+    
+    /// Time of the change
+    pub timestamp: i64,
+}
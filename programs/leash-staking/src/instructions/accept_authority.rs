@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Proposed authority taking over
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let global_staking_state = &mut ctx.accounts.global_staking_state;
+    let new_authority = ctx.accounts.new_authority.key();
+    
+    let old_authority = global_staking_state.authority;
+    global_staking_state.accept_authority(&new_authority)?;
+    
+    emit!(AuthorityTransferred {
+        global_staking_state: global_staking_state.key(),
+        old_authority,
+        new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Authority transferred: {} -> {}", old_authority, new_authority);
+    
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct AddRewardStream<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Mint of the reward token paid by the new stream
+    pub reward_mint: Account<'info, Mint>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// Vault holding the new stream's rewards
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"reward_stream_vault", global_staking_state.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = vault_authority,
+    )]
+    pub stream_vault: Account<'info, TokenAccount>,
+    
+    /// Authority that can update staking parameters
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+    
+    /// Rent sysvar for account creation
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<AddRewardStream>, reward_rate: u64) -> Result<()> {
+    apply_reward_stream(
+        &mut ctx.accounts.global_staking_state,
+        &ctx.accounts.emission_schedule,
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.stream_vault.key(),
+        reward_rate,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Settle rewards, register a reward stream and emit `RewardStreamAdded`
+pub fn apply_reward_stream(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    emission_schedule: &EmissionSchedule,
+    reward_mint: Pubkey,
+    stream_vault: Pubkey,
+    reward_rate: u64,
+    current_time: i64,
+) -> Result<()> {
+    // A new stream changes what stakers earn, so it waits for the timelock to be cleared
+    require!(
+        global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    require!(
+        reward_rate <= MAX_REWARD_RATE,
+        StakingError::InvalidRewardRate
+    );
+    
+    // Settle existing streams so the new one starts accruing now
+    global_staking_state.update_rewards(emission_schedule, current_time)?;
+    
+    let index = global_staking_state.add_reward_stream(reward_mint, stream_vault, reward_rate)?;
+    
+    emit!(RewardStreamAdded {
+        global_staking_state: global_staking_state.key(),
+        index,
+        reward_mint,
+        stream_vault,
+        reward_rate,
+        timestamp: current_time,
+    });
+    
+    msg!("Added reward stream {} for mint {}", index, reward_mint);
+    msg!("Reward rate: {}", reward_rate);
+    
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ApproveMultisigProposal<'info> {
+    #[account(
+        seeds = [b"multisig", multisig.global_staking_state.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    /// Proposal being acted on
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            multisig.key().as_ref(),
+            &proposal.id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        has_one = multisig,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    /// Member approving the proposal
+    pub member: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let member = ctx.accounts.member.key();
+    let member_index = multisig.member_index(&member)?;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.member_set_version == multisig.member_set_version,
+        StakingError::StaleMultisigProposal
+    );
+    proposal.approve(member_index)?;
+    
+    emit!(MultisigProposalApproved {
+        multisig: multisig.key(),
+        id: proposal.id,
+        member,
+        approvals: proposal.approval_count() as u8,
+        threshold: multisig.threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Multisig proposal {} approved by {} ({}/{})", proposal.id, member, proposal.approval_count(), multisig.threshold);
+    
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CancelParamChange<'info> {
+    #[account(
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        constraint = global_staking_state.can_cancel_param_change(&signer.key()) @ StakingError::Unauthorized,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Queued change being closed
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            b"pending_param_change",
+            global_staking_state.key().as_ref(),
+            &pending_param_change.id.to_le_bytes(),
+        ],
+        bump = pending_param_change.bump,
+        has_one = global_staking_state,
+        has_one = proposer,
+    )]
+    pub pending_param_change: Account<'info, PendingParamChange>,
+    
+    /// Account that queued the change, refunded the rent
+    /// CHECK: Matched against `pending_param_change.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    /// Authority or guardian cancelling the change
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelParamChange>) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    emit!(ParamChangeCancelled {
+        global_staking_state: accounts.global_staking_state.key(),
+        id: accounts.pending_param_change.id,
+        signer: accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Parameter change {} cancelled", accounts.pending_param_change.id);
+    
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CancelUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = xleash_mint,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// User's staking position
+    #[account(
+        mut,
+        seeds = [
+            b"user_staking_position",
+            user.key().as_ref(),
+            global_staking_state.key().as_ref()
+        ],
+        bump = user_staking_position.bump,
+        constraint = user_staking_position.owner == user.key() @ StakingError::Unauthorized,
+        has_one = global_staking_state,
+    )]
+    pub user_staking_position: Account<'info, UserStakingPosition>,
+    
+    /// User's xLEASH token account
+    #[account(
+        mut,
+        constraint = user_xleash_account.mint == xleash_mint.key(),
+        constraint = user_xleash_account.owner == user.key(),
+    )]
+    pub user_xleash_account: Account<'info, TokenAccount>,
+    
+    /// xLEASH token mint
+    #[account(mut)]
+    pub xleash_mint: Account<'info, Mint>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// User signing the transaction
+    pub user: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelUnstake<'info> {
+    pub fn validate(&self, index: u8) -> Result<()> {
+        // Re-staking is blocked while staking is paused
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_STAKE),
+            StakingError::StakingPaused
+        );
+        
+        // Check if staking pool has capacity for the returned LEASH
+        let request = self.user_staking_position.unbonding_request(index)?;
+        self.global_staking_state.check_stake_capacity(request.amount)?;
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CancelUnstake>, index: u8) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the cancellation
+    accounts.validate(index)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
+    
+    // Update global rewards before re-staking
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    
+    // Re-stake the queued LEASH at the current exchange rate
+    let request = accounts.user_staking_position.unbonding_request(index)?;
+    let xleash_amount = global_staking_state.xleash_for_deposit(request.amount)?;
+    
+    // Mint xLEASH to user, signed by the vault authority
+    let mint_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        MintTo {
+            mint: accounts.xleash_mint.to_account_info(),
+            to: accounts.user_xleash_account.to_account_info(),
+            authority: accounts.vault_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::mint_to(mint_ctx, xleash_amount)?;
+    
+    // Update rewards before re-staking, then return the request as an unlocked deposit
+    let user_position = &mut accounts.user_staking_position;
+    user_position.settle_rewards(global_staking_state, current_time)?;
+    let old_weight = user_position.weighted_stake;
+    
+    let was_active = user_position.staked_amount > 0;
+    let leash_amount = user_position.cancel_unstake(index, xleash_amount, current_time)?;
+    user_position.expire_boosts(current_time)?;
+    global_staking_state.apply_weight_change(old_weight, user_position.weighted_stake)?;
+    
+    if !was_active && user_position.staked_amount > 0 {
+        global_staking_state.active_stakers = global_staking_state.active_stakers
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
+    }
+    
+    // Move the LEASH from unbonding back to staked
+    global_staking_state.total_unbonding = global_staking_state.total_unbonding
+        .checked_sub(leash_amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    global_staking_state.total_staked = global_staking_state.total_staked
+        .checked_add(leash_amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    global_staking_state.total_xleash_minted = global_staking_state.total_xleash_minted
+        .checked_add(xleash_amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    emit!(UnstakeCancelled {
+        user: accounts.user.key(),
+        global_staking_state: global_staking_state_key,
+        amount: leash_amount,
+        xleash_amount,
+        user_staked_amount: user_position.staked_amount,
+        total_staked: global_staking_state.total_staked,
+        total_unbonding: global_staking_state.total_unbonding,
+        timestamp: current_time,
+    });
+    
+    msg!("Cancelled unstake of {} LEASH", leash_amount);
+    msg!("Received {} xLEASH tokens", xleash_amount);
+    msg!("Total staked: {} LEASH", global_staking_state.total_staked);
+    
+    Ok(())
+}
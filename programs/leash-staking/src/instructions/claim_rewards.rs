@@ -1,19 +1,32 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
     #[account(
         mut,
-        seeds = [b"global_staking_state"],
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
         bump = global_staking_state.bump,
         has_one = leash_mint,
+        has_one = reward_vault,
     )]
     pub global_staking_state: Account<'info, GlobalStakingState>,
     
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
     /// User's staking position
     #[account(
         mut,
@@ -23,7 +36,7 @@ pub struct ClaimRewards<'info> {
             global_staking_state.key().as_ref()
         ],
         bump = user_staking_position.bump,
-        has_one = owner,
+        constraint = user_staking_position.owner == user.key() @ StakingError::Unauthorized,
         has_one = global_staking_state,
     )]
     pub user_staking_position: Account<'info, UserStakingPosition>,
@@ -39,12 +52,17 @@ pub struct ClaimRewards<'info> {
     /// LEASH token mint
     pub leash_mint: Account<'info, Mint>,
     
-    /// Treasury account for distributing rewards
+    /// Vault holding rewards owed to stakers
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
     #[account(
-        mut,
-        constraint = treasury.key() == global_staking_state.treasury,
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
     )]
-    pub treasury: Account<'info, TokenAccount>,
+    pub vault_authority: UncheckedAccount<'info>,
     
     /// User signing the transaction
     pub user: Signer<'info>,
@@ -56,12 +74,9 @@ pub struct ClaimRewards<'info> {
 impl<'info> ClaimRewards<'info> {
     pub fn validate(&self) -> Result<()> {
         // Check if staking is paused
-        require!(!self.global_staking_state.is_paused, StakingError::StakingPaused);
-        
-        // Check if user has any staked tokens
         require!(
-            self.user_staking_position.staked_amount > 0,
-            StakingError::InsufficientBalance
+            !self.global_staking_state.is_operation_paused(PAUSE_CLAIM),
+            StakingError::StakingPaused
         );
         
         Ok(())
@@ -69,18 +84,28 @@ impl<'info> ClaimRewards<'info> {
 }
 
 pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
-    let accounts = &ctx.accounts;
+    let accounts = ctx.accounts;
     
     // Validate the claim operation
     accounts.validate()?;
     
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
+    
     // Update global rewards
     let global_staking_state = &mut accounts.global_staking_state;
-    global_staking_state.update_rewards();
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
     
     // Update user rewards and calculate claimable amount
     let user_position = &mut accounts.user_staking_position;
-    user_position.update_rewards(global_staking_state);
+    // Boosts stop earning once their tranche unlocks
+    user_position.settle_rewards(global_staking_state, current_time)?;
     
     let claimable_amount = user_position.pending_rewards;
     
@@ -90,25 +115,38 @@ pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
         StakingError::NoRewardsToClaim
     );
     
-    // Transfer rewards from treasury to user
-    let transfer_ctx = CpiContext::new(
+    // Never pay out more than the reward vault holds
+    require!(
+        accounts.reward_vault.amount >= claimable_amount,
+        StakingError::InsufficientRewardFunds
+    );
+    
+    // Transfer rewards from the reward vault to user, signed by the vault authority
+    let transfer_ctx = CpiContext::new_with_signer(
         accounts.token_program.to_account_info(),
         Transfer {
-            from: accounts.treasury.to_account_info(),
+            from: accounts.reward_vault.to_account_info(),
             to: accounts.user_leash_account.to_account_info(),
-            authority: accounts.treasury.to_account_info(),
+            authority: accounts.vault_authority.to_account_info(),
         },
+        signer_seeds,
     );
     
     token::transfer(transfer_ctx, claimable_amount)?;
     
     // Update user position - mark rewards as claimed
-    user_position.claim_rewards(claimable_amount);
+    user_position.claim_rewards(claimable_amount, current_time)?;
     
     // Update global statistics
-    global_staking_state.total_rewards_distributed = global_staking_state.total_rewards_distributed
-        .checked_add(claimable_amount)
-        .ok_or(StakingError::MathOverflow)?;
+    global_staking_state.record_rewards_paid(claimable_amount)?;
+    
+    emit!(RewardsClaimed {
+        user: accounts.user.key(),
+        global_staking_state: global_staking_state_key,
+        amount: claimable_amount,
+        total_rewards_distributed: global_staking_state.total_rewards_distributed,
+        timestamp: current_time,
+    });
     
     msg!("Successfully claimed {} LEASH rewards", claimable_amount);
     msg!("Total rewards distributed: {} LEASH", global_staking_state.total_rewards_distributed);
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ClaimStreamRewards<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// User's staking position
+    #[account(
+        mut,
+        seeds = [
+            b"user_staking_position",
+            user.key().as_ref(),
+            global_staking_state.key().as_ref()
+        ],
+        bump = user_staking_position.bump,
+        constraint = user_staking_position.owner == user.key() @ StakingError::Unauthorized,
+        has_one = global_staking_state,
+    )]
+    pub user_staking_position: Account<'info, UserStakingPosition>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// User signing the transaction
+    pub user: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimStreamRewards<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // Check if claiming is paused
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_CLAIM),
+            StakingError::StakingPaused
+        );
+        
+        Ok(())
+    }
+}
+
+/// Claims one reward stream, or every active stream when `index` is `None`
+/// Remaining accounts are (stream vault, user token account) pairs for each claimed stream, in index order
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimStreamRewards<'info>>,
+    index: Option<u8>,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let accounts = ctx.accounts;
+    
+    // Validate the claim
+    accounts.validate()?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
+    
+    // Update global and user rewards
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    
+    let user_position = &mut accounts.user_staking_position;
+    user_position.settle_rewards(global_staking_state, current_time)?;
+    
+    let stream_indices: Vec<usize> = match index {
+        Some(index) => {
+            global_staking_state.reward_stream_mut(index)?;
+            vec![index as usize]
+        }
+        None => (0..MAX_REWARD_STREAMS)
+            .filter(|index| global_staking_state.reward_streams[*index].is_active())
+            .collect(),
+    };
+    
+    require!(
+        remaining_accounts.len() == stream_indices.len() * 2,
+        StakingError::InvalidRewardStream
+    );
+    
+    let mut streams_claimed = 0;
+    
+    for (stream_index, pair) in stream_indices.into_iter().zip(remaining_accounts.chunks(2)) {
+        let stream = &mut global_staking_state.reward_streams[stream_index];
+        let stream_vault = Account::<TokenAccount>::try_from(&pair[0])?;
+        let user_token_account = Account::<TokenAccount>::try_from(&pair[1])?;
+        
+        require_keys_eq!(stream_vault.key(), stream.vault, StakingError::InvalidRewardStream);
+        require!(
+            user_token_account.mint == stream.mint && user_token_account.owner == accounts.user.key(),
+            StakingError::InvalidRewardStream
+        );
+        
+        let claimable_amount = user_position.stream_rewards[stream_index].pending_rewards;
+        if claimable_amount == 0 {
+            continue;
+        }
+        
+        // Never pay out more than the stream vault holds
+        require!(
+            stream_vault.amount >= claimable_amount,
+            StakingError::InsufficientRewardFunds
+        );
+        
+        // Transfer rewards from the stream vault to user, signed by the vault authority
+        let transfer_ctx = CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: stream_vault.to_account_info(),
+                to: user_token_account.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, claimable_amount)?;
+        
+        user_position.claim_stream_rewards(stream_index, claimable_amount)?;
+        stream.total_distributed = stream.total_distributed
+            .checked_add(claimable_amount)
+            .ok_or(StakingError::MathOverflow)?;
+        streams_claimed += 1;
+        
+        emit!(StreamRewardsClaimed {
+            user: accounts.user.key(),
+            global_staking_state: global_staking_state_key,
+            index: stream_index as u8,
+            reward_mint: stream.mint,
+            amount: claimable_amount,
+            total_distributed: stream.total_distributed,
+            timestamp: current_time,
+        });
+        
+        msg!("Claimed {} from reward stream {}", claimable_amount, stream_index);
+    }
+    
+    // Check if there were rewards to claim
+    require!(
+        streams_claimed > 0,
+        StakingError::NoRewardsToClaim
+    );
+    
+    Ok(())
+}
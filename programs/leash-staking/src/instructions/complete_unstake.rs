@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CompleteUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = leash_mint,
+        has_one = stake_vault,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// User's staking position
+    #[account(
+        mut,
+        seeds = [
+            b"user_staking_position",
+            user.key().as_ref(),
+            global_staking_state.key().as_ref()
+        ],
+        bump = user_staking_position.bump,
+        constraint = user_staking_position.owner == user.key() @ StakingError::Unauthorized,
+        has_one = global_staking_state,
+    )]
+    pub user_staking_position: Account<'info, UserStakingPosition>,
+    
+    /// User's LEASH token account
+    #[account(
+        mut,
+        constraint = user_leash_account.mint == leash_mint.key(),
+        constraint = user_leash_account.owner == user.key(),
+    )]
+    pub user_leash_account: Account<'info, TokenAccount>,
+    
+    /// LEASH token mint
+    pub leash_mint: Account<'info, Mint>,
+    
+    /// Vault holding staked and unbonding LEASH
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// User signing the transaction
+    pub user: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CompleteUnstake<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // Check if unstaking is paused
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_UNSTAKE),
+            StakingError::StakingPaused
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CompleteUnstake>, index: u8) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the withdrawal
+    accounts.validate()?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
+    
+    // Remove the request once its cooldown has elapsed
+    let leash_amount = accounts.user_staking_position.complete_unstake(index, current_time)?;
+    
+    // Transfer LEASH from the stake vault to user, signed by the vault authority
+    let transfer_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.stake_vault.to_account_info(),
+            to: accounts.user_leash_account.to_account_info(),
+            authority: accounts.vault_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::transfer(transfer_ctx, leash_amount)?;
+    
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.total_unbonding = global_staking_state.total_unbonding
+        .checked_sub(leash_amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    // Stake vault must still back all staked and unbonding principal
+    accounts.stake_vault.reload()?;
+    global_staking_state.check_principal_invariant(accounts.stake_vault.amount)?;
+    
+    emit!(Unstaked {
+        user: accounts.user.key(),
+        global_staking_state: global_staking_state_key,
+        amount: leash_amount,
+        total_staked: global_staking_state.total_staked,
+        total_unbonding: global_staking_state.total_unbonding,
+        timestamp: current_time,
+    });
+    
+    msg!("Successfully unstaked {} LEASH tokens", leash_amount);
+    msg!("Total unbonding: {} LEASH", global_staking_state.total_unbonding);
+    
+    Ok(())
+}
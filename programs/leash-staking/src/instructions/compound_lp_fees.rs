@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CompoundLpFees<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        constraint = global_staking_state.can_distribute_fees(&distributor.key()) @ StakingError::Unauthorized,
+        has_one = stake_vault,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Vault holding staked LEASH principal
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// Token account the LP fees are paid from
+    #[account(
+        mut,
+        constraint = fee_source.mint == global_staking_state.leash_mint,
+        constraint = fee_source.owner == distributor.key(),
+    )]
+    pub fee_source: Account<'info, TokenAccount>,
+    
+    /// Authority or fee distributor paying in the fees
+    pub distributor: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CompoundLpFees<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // Check if staking is paused
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_DISTRIBUTE),
+            StakingError::StakingPaused
+        );
+        
+        // Validate compound amount
+        require!(
+            amount > 0,
+            StakingError::InvalidLpFeeDistribution
+        );
+        
+        // Check if the fee source has sufficient balance
+        require!(
+            self.fee_source.amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CompoundLpFees>, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the compound operation
+    accounts.validate(amount)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Transfer LP fees into the stake vault
+    let transfer_ctx = CpiContext::new(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.fee_source.to_account_info(),
+            to: accounts.stake_vault.to_account_info(),
+            authority: accounts.distributor.to_account_info(),
+        },
+    );
+    
+    token::transfer(transfer_ctx, amount)?;
+    
+    // Settle emissions before the pool grows, then raise the exchange rate
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    global_staking_state.compound_lp_fees(amount)?;
+    
+    // Stake vault must still back all staked principal
+    accounts.stake_vault.reload()?;
+    global_staking_state.check_principal_invariant(accounts.stake_vault.amount)?;
+    
+    emit!(LpFeesCompounded {
+        global_staking_state: global_staking_state.key(),
+        distributor: accounts.distributor.key(),
+        amount,
+        exchange_rate: global_staking_state.exchange_rate()?,
+        total_staked: global_staking_state.total_staked,
+        timestamp: current_time,
+    });
+    
+    msg!("Compounded {} LEASH in LP fees into the pool", amount);
+    msg!("xLEASH exchange rate: {}", global_staking_state.exchange_rate()?);
+    msg!("Total staked: {} LEASH", global_staking_state.total_staked);
+    
+    Ok(())
+}
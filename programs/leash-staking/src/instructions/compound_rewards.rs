@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, MintTo};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CompoundRewards<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = xleash_mint,
+        has_one = stake_vault,
+        has_one = reward_vault,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Position whose rewards are compounded
+    /// The owner may always compound; anyone else only if the owner opted in
+    #[account(
+        mut,
+        seeds = [
+            b"user_staking_position",
+            owner.key().as_ref(),
+            global_staking_state.key().as_ref()
+        ],
+        bump = user_staking_position.bump,
+        has_one = owner,
+        has_one = global_staking_state,
+        constraint = signer.key() == owner.key() || user_staking_position.auto_compound @ StakingError::Unauthorized,
+    )]
+    pub user_staking_position: Account<'info, UserStakingPosition>,
+    
+    /// Owner of the position
+    /// CHECK: Checked against the position's owner
+    pub owner: UncheckedAccount<'info>,
+    
+    /// Owner's xLEASH token account
+    #[account(
+        mut,
+        constraint = owner_xleash_account.mint == xleash_mint.key(),
+        constraint = owner_xleash_account.owner == owner.key(),
+    )]
+    pub owner_xleash_account: Account<'info, TokenAccount>,
+    
+    /// xLEASH token mint
+    #[account(mut)]
+    pub xleash_mint: Account<'info, Mint>,
+    
+    /// Vault holding staked LEASH principal
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// Vault holding rewards owed to stakers
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// Owner, or a cranker for positions that opted in
+    pub signer: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CompoundRewards<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // Compounding both claims and stakes
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_CLAIM | PAUSE_STAKE),
+            StakingError::StakingPaused
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CompoundRewards>) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the compound operation
+    accounts.validate()?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
+    
+    // Update global and user rewards
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    
+    let user_position = &mut accounts.user_staking_position;
+    user_position.settle_rewards(global_staking_state, current_time)?;
+    let old_weight = user_position.weighted_stake;
+    
+    let amount = user_position.pending_rewards;
+    
+    // Check if there are rewards to compound
+    require!(
+        amount > 0,
+        StakingError::NoRewardsToClaim
+    );
+    
+    // Never move more than the reward vault holds, and respect staking limits
+    require!(
+        accounts.reward_vault.amount >= amount,
+        StakingError::InsufficientRewardFunds
+    );
+    global_staking_state.check_stake_capacity(amount)?;
+    
+    // Calculate xLEASH amount to mint at the current exchange rate
+    let xleash_amount = global_staking_state.xleash_for_deposit(amount)?;
+    
+    // Move rewards from the reward vault straight into the stake vault
+    let transfer_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.reward_vault.to_account_info(),
+            to: accounts.stake_vault.to_account_info(),
+            authority: accounts.vault_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::transfer(transfer_ctx, amount)?;
+    
+    // Mint xLEASH to the owner, signed by the vault authority
+    let mint_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        MintTo {
+            mint: accounts.xleash_mint.to_account_info(),
+            to: accounts.owner_xleash_account.to_account_info(),
+            authority: accounts.vault_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::mint_to(mint_ctx, xleash_amount)?;
+    
+    // Restake the rewards unlocked, so compounding never extends a lock
+    let was_active = user_position.staked_amount > 0;
+    user_position.compound_rewards(amount, xleash_amount, 0, current_time)?;
+    global_staking_state.apply_weight_change(old_weight, user_position.weighted_stake)?;
+    
+    if !was_active && user_position.staked_amount > 0 {
+        global_staking_state.active_stakers = global_staking_state.active_stakers
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
+    }
+    
+    // Update global state
+    global_staking_state.total_staked = global_staking_state.total_staked
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    global_staking_state.total_xleash_minted = global_staking_state.total_xleash_minted
+        .checked_add(xleash_amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    global_staking_state.record_rewards_paid(amount)?;
+    
+    // Stake vault must still back all staked principal
+    accounts.stake_vault.reload()?;
+    global_staking_state.check_principal_invariant(accounts.stake_vault.amount)?;
+    
+    emit!(RewardsCompounded {
+        user: user_position.owner,
+        global_staking_state: global_staking_state_key,
+        caller: accounts.signer.key(),
+        amount,
+        xleash_amount,
+        keeper_fee: 0,
+        user_staked_amount: user_position.staked_amount,
+        total_staked: global_staking_state.total_staked,
+        timestamp: current_time,
+    });
+    
+    msg!("Compounded {} LEASH rewards", amount);
+    msg!("Received {} xLEASH tokens", xleash_amount);
+    msg!("Total staked: {} LEASH", global_staking_state.total_staked);
+    
+    Ok(())
+}
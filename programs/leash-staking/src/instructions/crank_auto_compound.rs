@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, MintTo};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CrankAutoCompound<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = leash_mint,
+        has_one = xleash_mint,
+        has_one = stake_vault,
+        has_one = reward_vault,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Keeper's LEASH token account for receiving fees
+    #[account(
+        mut,
+        constraint = keeper_leash_account.mint == leash_mint.key(),
+        constraint = keeper_leash_account.owner == keeper.key(),
+    )]
+    pub keeper_leash_account: Account<'info, TokenAccount>,
+    
+    /// LEASH token mint
+    pub leash_mint: Account<'info, Mint>,
+    
+    /// xLEASH token mint
+    #[account(mut)]
+    pub xleash_mint: Account<'info, Mint>,
+    
+    /// Vault holding staked LEASH principal
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// Vault holding rewards owed to stakers
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// Keeper running the crank
+    pub keeper: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CrankAutoCompound<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        // Compounding both claims and stakes
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_CLAIM | PAUSE_STAKE),
+            StakingError::StakingPaused
+        );
+        
+        // Positions come in (position, owner xLEASH account) pairs
+        require!(
+            !remaining_accounts.is_empty() && remaining_accounts.chunks_exact(2).remainder().is_empty(),
+            StakingError::InvalidCrankAccounts
+        );
+        
+        Ok(())
+    }
+}
+
+/// Compounds every opted-in position passed as remaining accounts
+/// Positions that opted out, have nothing pending, or no longer fit the pool are skipped
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CrankAutoCompound<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let accounts = ctx.accounts;
+    
+    // Validate the crank
+    accounts.validate(remaining_accounts)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
+    
+    // Update global rewards once for the whole batch
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    
+    let mut total_compounded: u64 = 0;
+    let mut total_keeper_fees: u64 = 0;
+    let mut positions_compounded: u32 = 0;
+    
+    for pair in remaining_accounts.chunks(2) {
+        let mut user_position = Account::<UserStakingPosition>::try_from(&pair[0])?;
+        require_keys_eq!(
+            user_position.global_staking_state,
+            global_staking_state_key,
+            StakingError::InvalidCrankAccounts
+        );
+        
+        if !user_position.auto_compound {
+            continue;
+        }
+        
+        let owner_xleash_account = Account::<TokenAccount>::try_from(&pair[1])?;
+        require!(
+            owner_xleash_account.mint == accounts.xleash_mint.key()
+                && owner_xleash_account.owner == user_position.owner,
+            StakingError::InvalidCrankAccounts
+        );
+        
+        // Settle rewards, then split off the keeper's fee
+        user_position.settle_rewards(global_staking_state, current_time)?;
+        let old_weight = user_position.weighted_stake;
+        
+        let pending_rewards = user_position.pending_rewards;
+        let keeper_fee = global_staking_state.keeper_fee(pending_rewards)?;
+        let amount = pending_rewards - keeper_fee;
+        
+        // A skipped position was still settled, so its checkpoint must be persisted
+        if amount == 0 || global_staking_state.check_stake_capacity(amount).is_err() {
+            user_position.exit(&crate::ID)?;
+            continue;
+        }
+        
+        // Mint xLEASH at the exchange rate as of this position
+        let xleash_amount = global_staking_state.xleash_for_deposit(amount)?;
+        
+        let mint_ctx = CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            MintTo {
+                mint: accounts.xleash_mint.to_account_info(),
+                to: owner_xleash_account.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::mint_to(mint_ctx, xleash_amount)?;
+        
+        // Restake the rewards unlocked, so keepers cannot extend the owner's locks,
+        // and pay the keeper out of the same pending balance
+        let was_active = user_position.staked_amount > 0;
+        user_position.compound_rewards(amount, xleash_amount, 0, current_time)?;
+        user_position.claim_rewards(keeper_fee, current_time)?;
+        global_staking_state.apply_weight_change(old_weight, user_position.weighted_stake)?;
+        
+        if !was_active && user_position.staked_amount > 0 {
+            global_staking_state.active_stakers = global_staking_state.active_stakers
+                .checked_add(1)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+        
+        global_staking_state.total_staked = global_staking_state.total_staked
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        global_staking_state.total_xleash_minted = global_staking_state.total_xleash_minted
+            .checked_add(xleash_amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        user_position.exit(&crate::ID)?;
+        
+        emit!(RewardsCompounded {
+            user: user_position.owner,
+            global_staking_state: global_staking_state_key,
+            caller: accounts.keeper.key(),
+            amount,
+            xleash_amount,
+            keeper_fee,
+            user_staked_amount: user_position.staked_amount,
+            total_staked: global_staking_state.total_staked,
+            timestamp: current_time,
+        });
+        
+        total_compounded = total_compounded
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        total_keeper_fees = total_keeper_fees
+            .checked_add(keeper_fee)
+            .ok_or(StakingError::MathOverflow)?;
+        positions_compounded += 1;
+    }
+    
+    // Never move more than the reward vault holds
+    let total_paid = total_compounded
+        .checked_add(total_keeper_fees)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    require!(
+        accounts.reward_vault.amount >= total_paid,
+        StakingError::InsufficientRewardFunds
+    );
+    
+    // Move the batch's rewards from the reward vault into the stake vault
+    if total_compounded > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.reward_vault.to_account_info(),
+                to: accounts.stake_vault.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, total_compounded)?;
+    }
+    
+    // Pay the keeper
+    if total_keeper_fees > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.reward_vault.to_account_info(),
+                to: accounts.keeper_leash_account.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, total_keeper_fees)?;
+    }
+    
+    global_staking_state.record_rewards_paid(total_paid)?;
+    
+    // Stake vault must still back all staked principal
+    accounts.stake_vault.reload()?;
+    global_staking_state.check_principal_invariant(accounts.stake_vault.amount)?;
+    
+    msg!("Compounded {} positions", positions_compounded);
+    msg!("Compounded {} LEASH rewards", total_compounded);
+    msg!("Keeper fees: {} LEASH", total_keeper_fees);
+    
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Multisig for this pool
+    #[account(
+        init,
+        payer = authority,
+        space = Multisig::LEN,
+        seeds = [b"multisig", global_staking_state.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    /// Authority creating the multisig
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateMultisig>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    Multisig::validate_members(&members, threshold)?;
+    
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.global_staking_state = ctx.accounts.global_staking_state.key();
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.proposal_count = 0;
+    multisig.member_set_version = 0;
+    multisig.bump = ctx.bumps.multisig;
+    
+    emit!(MultisigCreated {
+        global_staking_state: multisig.global_staking_state,
+        multisig: multisig.key(),
+        members: multisig.members.clone(),
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Multisig {} created: {}-of-{}", multisig.key(), threshold, multisig.members.len());
+    
+    Ok(())
+}
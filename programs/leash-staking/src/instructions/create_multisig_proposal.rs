@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CreateMultisigProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.global_staking_state.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    /// New proposal, approved by its proposer
+    #[account(
+        init,
+        payer = member,
+        space = MultisigProposal::LEN,
+        seeds = [
+            b"multisig_proposal",
+            multisig.key().as_ref(),
+            &multisig.proposal_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    /// Member creating the proposal
+    #[account(mut)]
+    pub member: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateMultisigProposal>, action: MultisigAction) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let member = ctx.accounts.member.key();
+    let member_index = multisig.member_index(&member)?;
+    let id = multisig.next_proposal_id()?;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.id = id;
+    proposal.proposer = member;
+    proposal.action = action;
+    proposal.member_set_version = multisig.member_set_version;
+    proposal.approvals = 0;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.approve(member_index)?;
+    
+    emit!(MultisigProposalCreated {
+        multisig: multisig.key(),
+        id,
+        proposer: member,
+        action,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Multisig proposal {} created by {}", id, member);
+    
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct DistributeLpFees<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = reward_vault,
+        constraint = global_staking_state.can_distribute_fees(&distributor.key()) @ StakingError::Unauthorized,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Vault receiving the LP fees for stakers
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// Token account the LP fees are paid from
+    #[account(
+        mut,
+        constraint = fee_source.mint == global_staking_state.leash_mint,
+        constraint = fee_source.owner == distributor.key(),
+    )]
+    pub fee_source: Account<'info, TokenAccount>,
+    
+    /// Authority or fee distributor paying in the fees
+    pub distributor: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DistributeLpFees<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // Check if staking is paused
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_DISTRIBUTE),
+            StakingError::StakingPaused
+        );
+        
+        // Validate distribution amount
+        require!(
+            amount > 0,
+            StakingError::InvalidLpFeeDistribution
+        );
+        
+        // Check if the fee source has sufficient balance
+        require!(
+            self.fee_source.amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<DistributeLpFees>, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the distribution
+    accounts.validate(amount)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Transfer LP fees into the reward vault
+    let transfer_ctx = CpiContext::new(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.fee_source.to_account_info(),
+            to: accounts.reward_vault.to_account_info(),
+            authority: accounts.distributor.to_account_info(),
+        },
+    );
+    
+    token::transfer(transfer_ctx, amount)?;
+    
+    // Settle emissions before folding in the fees
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    global_staking_state.add_lp_fees(amount)?;
+    
+    emit!(LpFeesDistributed {
+        global_staking_state: global_staking_state.key(),
+        distributor: accounts.distributor.key(),
+        amount,
+        lp_fees_collected: global_staking_state.lp_fees_collected,
+        undistributed_lp_fees: global_staking_state.undistributed_lp_fees,
+        accumulated_rewards_per_token: global_staking_state.accumulated_rewards_per_token,
+        total_weighted_stake: global_staking_state.total_weighted_stake,
+        timestamp: current_time,
+    });
+    
+    msg!("Distributed {} LEASH in LP fees to stakers", amount);
+    msg!("Undistributed LP fees carried forward: {}", global_staking_state.undistributed_lp_fees);
+    msg!("Total LP fees collected: {}", global_staking_state.lp_fees_collected);
+    
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct DistributeStreamFees<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        constraint = global_staking_state.can_distribute_fees(&distributor.key()) @ StakingError::Unauthorized,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Vault receiving the fees for stakers
+    #[account(
+        mut,
+        constraint = (index as usize) < MAX_REWARD_STREAMS @ StakingError::InvalidRewardStream,
+        constraint = stream_vault.key() == global_staking_state.reward_streams[index as usize].vault @ StakingError::InvalidRewardStream,
+    )]
+    pub stream_vault: Account<'info, TokenAccount>,
+    
+    /// Token account the fees are paid from
+    #[account(
+        mut,
+        constraint = fee_source.mint == stream_vault.mint,
+        constraint = fee_source.owner == distributor.key(),
+    )]
+    pub fee_source: Account<'info, TokenAccount>,
+    
+    /// Authority or fee distributor paying in the fees
+    pub distributor: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DistributeStreamFees<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // Check if distribution is paused
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_DISTRIBUTE),
+            StakingError::StakingPaused
+        );
+        
+        // Validate distribution amount
+        require!(
+            amount > 0,
+            StakingError::InvalidLpFeeDistribution
+        );
+        
+        // Check if the fee source has sufficient balance
+        require!(
+            self.fee_source.amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<DistributeStreamFees>, index: u8, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the distribution
+    accounts.validate(amount)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Transfer fees in their native token to the stream vault
+    let transfer_ctx = CpiContext::new(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.fee_source.to_account_info(),
+            to: accounts.stream_vault.to_account_info(),
+            authority: accounts.distributor.to_account_info(),
+        },
+    );
+    
+    token::transfer(transfer_ctx, amount)?;
+    
+    // Settle emissions, then fold the fees into the stream's rewards per token
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    
+    let global_staking_state_key = global_staking_state.key();
+    let total_weighted_stake = global_staking_state.total_weighted_stake;
+    let stream = global_staking_state.reward_stream_mut(index)?;
+    stream.distribute(amount, total_weighted_stake)?;
+    stream.total_funded = stream.total_funded
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    emit!(StreamFeesDistributed {
+        global_staking_state: global_staking_state_key,
+        index,
+        distributor: accounts.distributor.key(),
+        amount,
+        accumulated_rewards_per_token: stream.accumulated_rewards_per_token,
+        total_funded: stream.total_funded,
+        timestamp: current_time,
+    });
+    
+    msg!("Distributed {} fees to reward stream {}", amount, index);
+    msg!("Accumulated rewards per token: {}", stream.accumulated_rewards_per_token);
+    
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, Burn};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct EarlyUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = leash_mint,
+        has_one = xleash_mint,
+        has_one = stake_vault,
+        has_one = reward_vault,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// User's staking position
+    #[account(
+        mut,
+        seeds = [
+            b"user_staking_position",
+            user.key().as_ref(),
+            global_staking_state.key().as_ref()
+        ],
+        bump = user_staking_position.bump,
+        constraint = user_staking_position.owner == user.key() @ StakingError::Unauthorized,
+        has_one = global_staking_state,
+    )]
+    pub user_staking_position: Account<'info, UserStakingPosition>,
+    
+    /// User's xLEASH token account
+    #[account(
+        mut,
+        constraint = user_xleash_account.mint == xleash_mint.key(),
+        constraint = user_xleash_account.owner == user.key(),
+    )]
+    pub user_xleash_account: Account<'info, TokenAccount>,
+    
+    /// LEASH token mint
+    pub leash_mint: Account<'info, Mint>,
+    
+    /// xLEASH token mint
+    #[account(mut)]
+    pub xleash_mint: Account<'info, Mint>,
+    
+    /// Vault holding staked LEASH principal
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// Vault paying out staking rewards
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// Pool treasury (a LEASH token account) receiving its share of the penalty
+    #[account(
+        mut,
+        constraint = treasury_leash_account.mint == leash_mint.key(),
+        constraint = treasury_leash_account.key() == global_staking_state.treasury @ StakingError::Unauthorized,
+    )]
+    pub treasury_leash_account: Account<'info, TokenAccount>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// User signing the transaction
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> EarlyUnstake<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // Check if unstaking is paused
+        require!(
+            !self.global_staking_state.is_operation_paused(PAUSE_UNSTAKE),
+            StakingError::StakingPaused
+        );
+        
+        // Validate unstake amount
+        require!(
+            amount > 0,
+            StakingError::InvalidStakeAmount
+        );
+        
+        // Check if user has sufficient xLEASH
+        require!(
+            self.user_xleash_account.amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        // Check if user's position holds enough xLEASH
+        require!(
+            self.user_staking_position.xleash_amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<EarlyUnstake>, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the early unstake
+    accounts.validate(amount)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
+    
+    // Update global rewards before unstaking
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
+    
+    // Update user rewards before unstaking
+    let user_position = &mut accounts.user_staking_position;
+    user_position.settle_rewards(global_staking_state, current_time)?;
+    let old_weight = user_position.weighted_stake;
+    
+    // Redeem xLEASH at the current exchange rate, and release the matching share of principal
+    let leash_amount = global_staking_state.leash_for_redemption(amount)?;
+    let principal_amount = user_position.principal_for_xleash(amount)?;
+    
+    // Burn xLEASH from user
+    let burn_ctx = CpiContext::new(
+        accounts.token_program.to_account_info(),
+        Burn {
+            mint: accounts.xleash_mint.to_account_info(),
+            from: accounts.user_xleash_account.to_account_info(),
+            authority: accounts.user.to_account_info(),
+        },
+    );
+    
+    token::burn(burn_ctx, amount)?;
+    
+    // Queue the redeemed LEASH net of the penalty for locked tranches
+    let penalty = user_position.early_unstake(
+        principal_amount,
+        amount,
+        leash_amount,
+        global_staking_state.early_exit_penalty_bps,
+        global_staking_state.unbonding_period,
+        current_time,
+    )?;
+    user_position.expire_boosts(current_time)?;
+    global_staking_state.apply_weight_change(old_weight, user_position.weighted_stake)?;
+    
+    // Drop the staker from the active count on full exit
+    if user_position.staked_amount == 0 {
+        global_staking_state.active_stakers = global_staking_state.active_stakers
+            .checked_sub(1)
+            .ok_or(StakingError::MathOverflow)?;
+    }
+    
+    // Move the LEASH from staked to unbonding, less the penalty paid out below
+    global_staking_state.total_staked = global_staking_state.total_staked
+        .checked_sub(leash_amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    global_staking_state.total_unbonding = global_staking_state.total_unbonding
+        .checked_add(leash_amount - penalty)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    global_staking_state.total_xleash_minted = global_staking_state.total_xleash_minted
+        .checked_sub(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    let (staker_share, treasury_share) = global_staking_state.split_penalty(penalty)?;
+    
+    // Redistribute the stakers' share through the reward vault, excluding the exiting user
+    if staker_share > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.stake_vault.to_account_info(),
+                to: accounts.reward_vault.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, staker_share)?;
+        global_staking_state.receive_rewards(staker_share)?;
+    }
+    
+    // Send the rest to the treasury
+    if treasury_share > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.stake_vault.to_account_info(),
+                to: accounts.treasury_leash_account.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, treasury_share)?;
+    }
+    
+    // Stake vault must still back all staked and unbonding principal
+    accounts.stake_vault.reload()?;
+    global_staking_state.check_principal_invariant(accounts.stake_vault.amount)?;
+    
+    let unlock_time = current_time
+        .checked_add(global_staking_state.unbonding_period)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    emit!(UnstakeRequested {
+        user: accounts.user.key(),
+        global_staking_state: global_staking_state_key,
+        xleash_amount: amount,
+        amount: leash_amount - penalty,
+        penalty,
+        unlock_time,
+        user_staked_amount: user_position.staked_amount,
+        total_staked: global_staking_state.total_staked,
+        total_unbonding: global_staking_state.total_unbonding,
+        timestamp: current_time,
+    });
+    
+    if penalty > 0 {
+        emit!(EarlyExitPenaltyPaid {
+            user: accounts.user.key(),
+            global_staking_state: global_staking_state_key,
+            penalty,
+            staker_share,
+            treasury_share,
+            timestamp: current_time,
+        });
+    }
+    
+    msg!("Requested early unstake of {} LEASH", leash_amount);
+    msg!("Burned {} xLEASH tokens", amount);
+    msg!("Penalty: {} LEASH ({} to stakers, {} to treasury)", penalty, staker_share, treasury_share);
+    
+    Ok(())
+}
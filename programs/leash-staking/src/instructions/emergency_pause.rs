@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct EmergencyPause<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        constraint = global_staking_state.can_pause(&signer.key()) @ StakingError::Unauthorized,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority or guardian toggling the pause flags
+    pub signer: Signer<'info>,
+}
+
+impl<'info> EmergencyPause<'info> {
+    pub fn validate(&self, operations: u8) -> Result<()> {
+        // Require at least one known operation flag
+        GlobalStakingState::validate_pause_flags(operations)
+    }
+}
+
+pub fn handler(
+    ctx: Context<EmergencyPause>,
+    operations: u8,
+    pause: bool,
+    reason_code: u16,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the requested flags
+    accounts.validate(operations)?;
+    
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.set_paused(operations, pause);
+    
+    emit!(PauseToggled {
+        global_staking_state: global_staking_state.key(),
+        signer: accounts.signer.key(),
+        operations,
+        paused: pause,
+        pause_flags: global_staking_state.pause_flags,
+        reason_code,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("{} operations {:#06b} (reason code {})", if pause { "Paused" } else { "Resumed" }, operations, reason_code);
+    msg!("Pause flags: {:#06b}", global_staking_state.pause_flags);
+    
+    Ok(())
+}
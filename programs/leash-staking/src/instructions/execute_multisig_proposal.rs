@@ -0,0 +1,372 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::add_reward_stream::apply_reward_stream;
+use crate::instructions::notify_reward_amount::start_reward_period;
+use crate::instructions::queue_param_change::record_param_change;
+use crate::instructions::renounce_authority::apply_renounce_authority;
+use crate::instructions::set_fee_distributor::apply_fee_distributor;
+use crate::instructions::set_guardian::apply_guardian;
+use crate::instructions::set_keeper_fee::apply_keeper_fee;
+use crate::instructions::set_rate_manager::apply_rate_manager;
+use crate::instructions::set_stream_reward_rate::apply_stream_reward_rate;
+use crate::instructions::update_lock_tiers::apply_lock_tiers;
+use crate::instructions::update_penalty_params::apply_penalty_params;
+use crate::instructions::update_staking_params::apply_param_change;
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Multisig acting as the authority or guardian
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.global_staking_state.as_ref()],
+        bump = multisig.bump,
+        has_one = global_staking_state,
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    /// Proposal being acted on
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            multisig.key().as_ref(),
+            &proposal.id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        has_one = multisig,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    /// Reward vault, for `NotifyRewardAmount`
+    #[account(
+        mut,
+        address = global_staking_state.reward_vault,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Change created by `QueueParamChange`
+    #[account(
+        init,
+        payer = payer,
+        space = PendingParamChange::LEN,
+        seeds = [
+            b"pending_param_change",
+            global_staking_state.key().as_ref(),
+            &global_staking_state.param_change_nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub pending_param_change: Option<Account<'info, PendingParamChange>>,
+    
+    /// Change closed by `CancelParamChange`
+    #[account(mut)]
+    pub cancelled_param_change: Option<Account<'info, PendingParamChange>>,
+    
+    /// Account refunded the cancelled change's rent, for `CancelParamChange`
+    /// CHECK: Matched against `cancelled_param_change.proposer`
+    #[account(mut)]
+    pub proposer: Option<UncheckedAccount<'info>>,
+    
+    /// Mint of the reward token, for `AddRewardStream`
+    #[account(address = proposal.action.reward_mint())]
+    pub reward_mint: Option<Account<'info, Mint>>,
+    
+    /// PDA that owns the program's token vaults, for `AddRewardStream`
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
+    )]
+    pub vault_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Vault created by `AddRewardStream`
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"reward_stream_vault",
+            global_staking_state.key().as_ref(),
+            proposal.action.reward_mint().as_ref(),
+        ],
+        bump,
+        token::mint = reward_mint,
+        token::authority = vault_authority,
+    )]
+    pub stream_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Pays the rent of accounts an action creates
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+    
+    /// Token program, for `AddRewardStream`
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// Rent sysvar, for `AddRewardStream`
+    pub rent: Option<Sysvar<'info, Rent>>,
+}
+
+pub fn handler(ctx: Context<ExecuteMultisigProposal>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let current_time = Clock::get()?.unix_timestamp;
+    let multisig_key = accounts.multisig.key();
+    let action = accounts.proposal.action;
+    
+    require!(!accounts.proposal.executed, StakingError::ProposalAlreadyExecuted);
+    require!(
+        accounts.proposal.member_set_version == accounts.multisig.member_set_version,
+        StakingError::StaleMultisigProposal
+    );
+    require!(
+        accounts.proposal.is_approved(accounts.multisig.threshold),
+        StakingError::ProposalNotApproved
+    );
+    
+    // Accounts are only created for the action that needs them
+    require!(
+        accounts.pending_param_change.is_some() == matches!(action, MultisigAction::QueueParamChange(_))
+            && accounts.stream_vault.is_some() == matches!(action, MultisigAction::AddRewardStream { .. })
+            && accounts.cancelled_param_change.is_some() == matches!(action, MultisigAction::CancelParamChange(_)),
+        StakingError::InvalidMultisigAccounts
+    );
+    
+    // Every action except pausing, cancelling and member changes needs the multisig to be the authority
+    let global_staking_state = &mut accounts.global_staking_state;
+    let needs_authority = !matches!(
+        action,
+        MultisigAction::SetPaused { .. }
+            | MultisigAction::CancelParamChange(_)
+            | MultisigAction::AcceptAuthority
+            | MultisigAction::AddMember(_)
+            | MultisigAction::RemoveMember(_)
+            | MultisigAction::ChangeThreshold(_)
+    );
+    require!(
+        !needs_authority || global_staking_state.authority == multisig_key,
+        StakingError::Unauthorized
+    );
+    
+    // The multisig address stands in for the signer the matching instruction requires
+    match action {
+        MultisigAction::UpdateStakingParams(change) => {
+            require!(global_staking_state.param_timelock == 0, StakingError::ParamChangeTimelocked);
+            
+            apply_param_change(
+                global_staking_state,
+                multisig_key,
+                &change,
+                current_time,
+            )?;
+        }
+        MultisigAction::SetPaused { operations, pause, reason_code } => {
+            require!(global_staking_state.can_pause(&multisig_key), StakingError::Unauthorized);
+            GlobalStakingState::validate_pause_flags(operations)?;
+            
+            global_staking_state.set_paused(operations, pause);
+            
+            emit!(PauseToggled {
+                global_staking_state: global_staking_state.key(),
+                signer: multisig_key,
+                operations,
+                paused: pause,
+                pause_flags: global_staking_state.pause_flags,
+                reason_code,
+                timestamp: current_time,
+            });
+        }
+        MultisigAction::ProposeAuthority(new_authority) => {
+            global_staking_state.propose_authority(new_authority);
+            
+            emit!(AuthorityProposed {
+                global_staking_state: global_staking_state.key(),
+                authority: multisig_key,
+                pending_authority: new_authority,
+                timestamp: current_time,
+            });
+        }
+        MultisigAction::AcceptAuthority => {
+            let old_authority = global_staking_state.authority;
+            global_staking_state.accept_authority(&multisig_key)?;
+            
+            emit!(AuthorityTransferred {
+                global_staking_state: global_staking_state.key(),
+                old_authority,
+                new_authority: multisig_key,
+                timestamp: current_time,
+            });
+        }
+        MultisigAction::RenounceAuthority => {
+            apply_renounce_authority(global_staking_state, current_time)?;
+        }
+        MultisigAction::QueueParamChange(change) => {
+            // The payer funded the rent, so it gets it back when the change closes
+            let pending_param_change = accounts.pending_param_change
+                .as_mut()
+                .ok_or(StakingError::InvalidMultisigAccounts)?;
+            
+            record_param_change(
+                global_staking_state,
+                pending_param_change,
+                accounts.payer.key(),
+                change,
+                ctx.bumps.pending_param_change,
+                current_time,
+            )?;
+        }
+        MultisigAction::NotifyRewardAmount { amount, duration } => {
+            let reward_vault = accounts.reward_vault
+                .as_mut()
+                .ok_or(StakingError::InvalidMultisigAccounts)?;
+            
+            start_reward_period(
+                global_staking_state,
+                &accounts.emission_schedule,
+                reward_vault,
+                multisig_key,
+                amount,
+                duration,
+                current_time,
+            )?;
+        }
+        MultisigAction::SetGuardian(guardian) => {
+            apply_guardian(global_staking_state, guardian, current_time)?;
+        }
+        MultisigAction::SetFeeDistributor(fee_distributor) => {
+            apply_fee_distributor(global_staking_state, fee_distributor, current_time)?;
+        }
+        MultisigAction::SetRateManager { rate_manager, min_reward_rate, max_reward_rate } => {
+            apply_rate_manager(
+                global_staking_state,
+                rate_manager,
+                min_reward_rate,
+                max_reward_rate,
+                current_time,
+            )?;
+        }
+        MultisigAction::UpdateLockTiers(lock_tiers) => {
+            apply_lock_tiers(global_staking_state, multisig_key, lock_tiers, current_time)?;
+        }
+        MultisigAction::UpdatePenaltyParams { early_exit_penalty_bps, penalty_staker_share_bps } => {
+            apply_penalty_params(
+                global_staking_state,
+                multisig_key,
+                early_exit_penalty_bps,
+                penalty_staker_share_bps,
+                current_time,
+            )?;
+        }
+        MultisigAction::SetKeeperFee(keeper_fee_bps) => {
+            apply_keeper_fee(global_staking_state, keeper_fee_bps, current_time)?;
+        }
+        MultisigAction::AddRewardStream { reward_mint, reward_rate } => {
+            let stream_vault = accounts.stream_vault
+                .as_ref()
+                .ok_or(StakingError::InvalidMultisigAccounts)?;
+            
+            apply_reward_stream(
+                global_staking_state,
+                &accounts.emission_schedule,
+                reward_mint,
+                stream_vault.key(),
+                reward_rate,
+                current_time,
+            )?;
+        }
+        MultisigAction::SetStreamRewardRate { index, reward_rate } => {
+            apply_stream_reward_rate(
+                global_staking_state,
+                &accounts.emission_schedule,
+                index,
+                reward_rate,
+                current_time,
+            )?;
+        }
+        MultisigAction::AddMember(member) => {
+            accounts.multisig.add_member(member)?;
+            emit_members_updated(&accounts.multisig, current_time);
+        }
+        MultisigAction::RemoveMember(member) => {
+            accounts.multisig.remove_member(member)?;
+            emit_members_updated(&accounts.multisig, current_time);
+        }
+        MultisigAction::ChangeThreshold(threshold) => {
+            accounts.multisig.change_threshold(threshold)?;
+            emit_members_updated(&accounts.multisig, current_time);
+        }
+        MultisigAction::CancelParamChange(id) => {
+            require!(
+                global_staking_state.can_cancel_param_change(&multisig_key),
+                StakingError::Unauthorized
+            );
+            
+            let cancelled_param_change = accounts.cancelled_param_change
+                .as_ref()
+                .ok_or(StakingError::InvalidMultisigAccounts)?;
+            let proposer = accounts.proposer
+                .as_ref()
+                .ok_or(StakingError::InvalidMultisigAccounts)?;
+            require!(
+                cancelled_param_change.global_staking_state == global_staking_state.key()
+                    && cancelled_param_change.id == id
+                    && cancelled_param_change.proposer == proposer.key(),
+                StakingError::InvalidMultisigAccounts
+            );
+            
+            cancelled_param_change.close(proposer.to_account_info())?;
+            
+            emit!(ParamChangeCancelled {
+                global_staking_state: global_staking_state.key(),
+                id,
+                signer: multisig_key,
+                timestamp: current_time,
+            });
+        }
+    }
+    
+    let proposal = &mut accounts.proposal;
+    proposal.executed = true;
+    
+    emit!(MultisigProposalExecuted {
+        multisig: multisig_key,
+        id: proposal.id,
+        timestamp: current_time,
+    });
+    
+    msg!("Multisig proposal {} executed", proposal.id);
+    
+    Ok(())
+}
+
+fn emit_members_updated(multisig: &Account<Multisig>, current_time: i64) {
+    emit!(MultisigMembersUpdated {
+        multisig: multisig.key(),
+        members: multisig.members.clone(),
+        threshold: multisig.threshold,
+        member_set_version: multisig.member_set_version,
+        timestamp: current_time,
+    });
+}
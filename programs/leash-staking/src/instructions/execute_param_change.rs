@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::update_staking_params::apply_param_change;
+
+#[derive(Accounts)]
+pub struct ExecuteParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Queued change being closed
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            b"pending_param_change",
+            global_staking_state.key().as_ref(),
+            &pending_param_change.id.to_le_bytes(),
+        ],
+        bump = pending_param_change.bump,
+        has_one = global_staking_state,
+        has_one = proposer,
+    )]
+    pub pending_param_change: Account<'info, PendingParamChange>,
+    
+    /// Account that queued the change, refunded the rent
+    /// CHECK: Matched against `pending_param_change.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteParamChange>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // A renounced pool's parameters stay frozen, even for changes queued earlier
+    require!(
+        !accounts.global_staking_state.is_renounced(),
+        StakingError::Unauthorized
+    );
+    
+    require!(
+        accounts.pending_param_change.is_ready(current_time),
+        StakingError::ParamChangeNotReady
+    );
+    
+    apply_param_change(
+        &mut accounts.global_staking_state,
+        accounts.pending_param_change.proposer,
+        &accounts.pending_param_change.change,
+        current_time,
+    )?;
+    
+    emit!(ParamChangeExecuted {
+        global_staking_state: accounts.global_staking_state.key(),
+        id: accounts.pending_param_change.id,
+        timestamp: current_time,
+    });
+    
+    msg!("Parameter change {} executed", accounts.pending_param_change.id);
+    
+    Ok(())
+}
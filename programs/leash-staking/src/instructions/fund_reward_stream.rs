@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct FundRewardStream<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Vault holding the stream's rewards
+    #[account(
+        mut,
+        constraint = (index as usize) < MAX_REWARD_STREAMS @ StakingError::InvalidRewardStream,
+        constraint = stream_vault.key() == global_staking_state.reward_streams[index as usize].vault @ StakingError::InvalidRewardStream,
+    )]
+    pub stream_vault: Account<'info, TokenAccount>,
+    
+    /// Token account the rewards are paid from
+    #[account(
+        mut,
+        constraint = funder_token_account.mint == stream_vault.mint,
+        constraint = funder_token_account.owner == funder.key(),
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    
+    /// Account topping up the stream vault
+    pub funder: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundRewardStream<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // Validate funding amount
+        require!(
+            amount > 0,
+            StakingError::InvalidStakeAmount
+        );
+        
+        // Check if the funder has sufficient balance
+        require!(
+            self.funder_token_account.amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<FundRewardStream>, index: u8, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the funding operation
+    accounts.validate(amount)?;
+    
+    // Transfer reward tokens from funder to the stream vault
+    let transfer_ctx = CpiContext::new(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.funder_token_account.to_account_info(),
+            to: accounts.stream_vault.to_account_info(),
+            authority: accounts.funder.to_account_info(),
+        },
+    );
+    
+    token::transfer(transfer_ctx, amount)?;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let stream = accounts.global_staking_state.reward_stream_mut(index)?;
+    stream.total_funded = stream.total_funded
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    emit!(RewardStreamFunded {
+        global_staking_state: global_staking_state_key,
+        index,
+        funder: accounts.funder.key(),
+        amount,
+        total_funded: stream.total_funded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Funded reward stream {} with {} tokens", index, amount);
+    msg!("Total funded: {}", stream.total_funded);
+    
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct FundRewards<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = reward_vault,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Vault holding rewards owed to stakers
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// Token account the rewards are paid from
+    #[account(
+        mut,
+        constraint = funder_leash_account.mint == global_staking_state.leash_mint,
+        constraint = funder_leash_account.owner == funder.key(),
+    )]
+    pub funder_leash_account: Account<'info, TokenAccount>,
+    
+    /// Account topping up the reward vault
+    pub funder: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundRewards<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // Validate funding amount
+        require!(
+            amount > 0,
+            StakingError::InvalidStakeAmount
+        );
+        
+        // Check if the funder has sufficient balance
+        require!(
+            self.funder_leash_account.amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<FundRewards>, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the funding operation
+    accounts.validate(amount)?;
+    
+    // Transfer LEASH from funder to the reward vault
+    let transfer_ctx = CpiContext::new(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.funder_leash_account.to_account_info(),
+            to: accounts.reward_vault.to_account_info(),
+            authority: accounts.funder.to_account_info(),
+        },
+    );
+    
+    token::transfer(transfer_ctx, amount)?;
+    
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.total_rewards_funded = global_staking_state.total_rewards_funded
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    emit!(RewardsFunded {
+        global_staking_state: global_staking_state.key(),
+        funder: accounts.funder.key(),
+        amount,
+        total_rewards_funded: global_staking_state.total_rewards_funded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Funded reward vault with {} LEASH", amount);
+    msg!("Total rewards funded: {} LEASH", global_staking_state.total_rewards_funded);
+    
+    Ok(())
+}
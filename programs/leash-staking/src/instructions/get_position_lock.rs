@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct GetPositionLock<'info> {
+    #[account(
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Staking position to query
+    #[account(has_one = global_staking_state)]
+    pub user_staking_position: Account<'info, UserStakingPosition>,
+}
+
+pub fn handler(ctx: Context<GetPositionLock>) -> Result<PositionLockStatus> {
+    let user_position = &ctx.accounts.user_staking_position;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    Ok(PositionLockStatus {
+        owner: user_position.owner,
+        staked_amount: user_position.staked_amount,
+        is_locked: !user_position.is_unlocked_at(current_time),
+        lock_end_time: user_position.lock_end_time,
+        seconds_until_unlock: user_position.seconds_until_unlock(current_time),
+        unlocked_amount: user_position.unlocked_amount(current_time)?,
+        weighted_stake: user_position.weighted_stake,
+        unbonding: user_position.unbonding.clone(),
+        deposits: user_position.deposits.clone(),
+        current_time,
+    })
+}
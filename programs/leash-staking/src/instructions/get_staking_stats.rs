@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct GetStakingStats<'info> {
+    #[account(
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+pub fn handler(ctx: Context<GetStakingStats>) -> Result<StakingStats> {
+    let global_staking_state = &ctx.accounts.global_staking_state;
+    let emission_schedule = &ctx.accounts.emission_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Project rewards to the current time; the state itself is left untouched
+    Ok(StakingStats {
+        pool_id: global_staking_state.pool_id,
+        leash_mint: global_staking_state.leash_mint,
+        authority: global_staking_state.authority,
+        pending_authority: global_staking_state.pending_authority,
+        guardian: global_staking_state.guardian,
+        fee_distributor: global_staking_state.fee_distributor,
+        rate_manager: global_staking_state.rate_manager,
+        total_staked: global_staking_state.total_staked,
+        total_xleash_minted: global_staking_state.total_xleash_minted,
+        total_weighted_stake: global_staking_state.total_weighted_stake,
+        period_finish: global_staking_state.period_finish,
+        total_unbonding: global_staking_state.total_unbonding,
+        reward_rate: if emission_schedule.is_active() {
+            emission_schedule.rate_at(current_time)
+        } else {
+            global_staking_state.reward_rate
+        },
+        accumulated_rewards_per_token: global_staking_state.rewards_per_token_at(emission_schedule, current_time)?,
+        exchange_rate: global_staking_state.exchange_rate()?,
+        total_rewards_distributed: global_staking_state.total_rewards_distributed,
+        lp_fees_collected: global_staking_state.lp_fees_collected,
+        active_stakers: global_staking_state.active_stakers,
+        is_paused: global_staking_state.is_paused,
+        pause_flags: global_staking_state.pause_flags,
+        current_time,
+    })
+}
@@ -1,29 +1,91 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
 #[derive(Accounts)]
+#[instruction(pool_id: u64)]
 pub struct Initialize<'info> {
+    /// Staking pool, keyed by its stake mint and pool id
     #[account(
         init,
         payer = authority,
         space = GlobalStakingState::LEN,
-        seeds = [b"global_staking_state"],
+        seeds = [
+            b"global_staking_state",
+            leash_mint.key().as_ref(),
+            &pool_id.to_le_bytes(),
+        ],
         bump
     )]
     pub global_staking_state: Account<'info, GlobalStakingState>,
     
-    /// LEASH token mint
+    /// Piecewise LEASH emission schedule (empty to use reward periods)
+    #[account(
+        init,
+        payer = authority,
+        space = EmissionSchedule::LEN,
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Mint staked in this pool (LEASH or an LP token)
     pub leash_mint: Account<'info, Mint>,
     
     /// xLEASH token mint (staking receipt token)
+    /// Must be freshly created with the vault authority as mint authority
+    #[account(
+        constraint = xleash_mint.mint_authority == COption::Some(vault_authority.key()) @ StakingError::InvalidXleashMint,
+        constraint = xleash_mint.supply == 0 @ StakingError::InvalidXleashMint,
+    )]
     pub xleash_mint: Account<'info, Mint>,
     
-    /// Treasury account for collecting fees
-    /// CHECK: This is the treasury account
-    pub treasury: UncheckedAccount<'info>,
+    /// Treasury token account receiving its share of early-exit penalties
+    #[account(token::mint = leash_mint)]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// PDA that owns the program's token vaults
+    /// CHECK: Derived from seeds, holds no data
+    #[account(
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// Vault holding staked LEASH principal
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"stake_vault", global_staking_state.key().as_ref()],
+        bump,
+        token::mint = leash_mint,
+        token::authority = vault_authority,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// Vault holding rewards owed to stakers
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"reward_vault", global_staking_state.key().as_ref()],
+        bump,
+        token::mint = leash_mint,
+        token::authority = vault_authority,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// Authority's LEASH account funding the emission schedule up front
+    /// Only needed when the schedule emits anything
+    #[account(
+        mut,
+        constraint = funder_leash_account.mint == leash_mint.key(),
+        constraint = funder_leash_account.owner == authority.key(),
+    )]
+    pub funder_leash_account: Option<Account<'info, TokenAccount>>,
     
     /// Authority that can update staking parameters
     #[account(mut)]
@@ -40,68 +102,134 @@ pub struct Initialize<'info> {
 }
 
 impl<'info> Initialize<'info> {
-    pub fn validate(&self) -> Result<()> {
-        // Validate reward rate is reasonable (not too high to prevent overflow)
-        require!(
-            self.reward_rate <= 1_000_000_000, // Max 1 token per second per staked token
-            StakingError::InvalidRewardRate
-        );
-        
-        // Validate stake amounts
-        require!(
-            self.min_stake_amount > 0,
-            StakingError::InvalidStakeAmount
-        );
+    pub fn validate(
+        &self,
+        reward_rate: u64,
+        min_stake_amount: u64,
+        max_stake_amount: u64,
+        lock_period: i64,
+        breakpoints: &[EmissionBreakpoint],
+    ) -> Result<()> {
+        GlobalStakingState::validate_params(
+            reward_rate,
+            min_stake_amount,
+            max_stake_amount,
+            lock_period,
+        )?;
         
-        require!(
-            self.max_stake_amount >= self.min_stake_amount,
-            StakingError::InvalidStakeAmount
-        );
-        
-        // Validate lock period (minimum 1 day, maximum 4 years)
-        require!(
-            self.lock_period >= 86400 && self.lock_period <= 126144000,
-            StakingError::InvalidStakeAmount
-        );
-        
-        Ok(())
+        EmissionSchedule::validate_breakpoints(breakpoints)
     }
 }
 
 pub fn handler(
     ctx: Context<Initialize>,
+    pool_id: u64,
     reward_rate: u64,
     min_stake_amount: u64,
     max_stake_amount: u64,
     lock_period: i64,
+    breakpoints: Vec<EmissionBreakpoint>,
 ) -> Result<()> {
-    let accounts = &ctx.accounts;
-    
     // Validate inputs
-    accounts.validate()?;
+    ctx.accounts.validate(reward_rate, min_stake_amount, max_stake_amount, lock_period, &breakpoints)?;
     
-    // Get bump seed
-    let bump = *ctx.bumps.get("global_staking_state").unwrap();
+    // Get bump seeds
+    let bump = ctx.bumps.global_staking_state;
+    let vault_authority_bump = ctx.bumps.vault_authority;
+    let emission_schedule_bump = ctx.bumps.emission_schedule;
+    let accounts = ctx.accounts;
+    let current_time = Clock::get()?.unix_timestamp;
     
     // Initialize global staking state
     let global_staking_state = &mut accounts.global_staking_state;
     global_staking_state.initialize(
+        pool_id,
         accounts.authority.key(),
         accounts.leash_mint.key(),
         accounts.xleash_mint.key(),
         accounts.treasury.key(),
+        accounts.stake_vault.key(),
+        accounts.reward_vault.key(),
         reward_rate,
         min_stake_amount,
         max_stake_amount,
         lock_period,
         bump,
+        vault_authority_bump,
+        current_time,
+    );
+    
+    // Store the emission schedule; while it has breakpoints it sets the reward rate
+    let emission_schedule = &mut accounts.emission_schedule;
+    emission_schedule.global_staking_state = global_staking_state.key();
+    emission_schedule.bump = emission_schedule_bump;
+    emission_schedule.breakpoints = breakpoints;
+    
+    if emission_schedule.is_active() {
+        global_staking_state.reward_rate = emission_schedule.rate_at(current_time);
+    }
+    
+    // The whole schedule is paid for when it is set, since nothing can top it up later
+    let scheduled_rewards = u64::try_from(
+        emission_schedule.remaining_emission(current_time).ok_or(StakingError::MathOverflow)?
+    ).map_err(|_| error!(StakingError::MathOverflow))?;
+    
+    if scheduled_rewards > 0 {
+        let funder_leash_account = accounts.funder_leash_account
+            .as_ref()
+            .ok_or(StakingError::InsufficientRewardFunds)?;
+        
+        let transfer_ctx = CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: funder_leash_account.to_account_info(),
+                to: accounts.reward_vault.to_account_info(),
+                authority: accounts.authority.to_account_info(),
+            },
+        );
+        
+        token::transfer(transfer_ctx, scheduled_rewards)?;
+        
+        global_staking_state.total_rewards_funded = global_staking_state.total_rewards_funded
+            .checked_add(scheduled_rewards)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        emit!(RewardsFunded {
+            global_staking_state: global_staking_state.key(),
+            funder: accounts.authority.key(),
+            amount: scheduled_rewards,
+            total_rewards_funded: global_staking_state.total_rewards_funded,
+            timestamp: current_time,
+        });
+    }
+    
+    accounts.reward_vault.reload()?;
+    require!(
+        scheduled_rewards <= global_staking_state.unowed_rewards(accounts.reward_vault.amount),
+        StakingError::InsufficientRewardFunds
     );
     
-    msg!("Staking program initialized successfully");
-    msg!("Reward rate: {} tokens per second per staked token", reward_rate);
+    emit!(PoolInitialized {
+        global_staking_state: global_staking_state.key(),
+        pool_id,
+        authority: global_staking_state.authority,
+        leash_mint: global_staking_state.leash_mint,
+        xleash_mint: global_staking_state.xleash_mint,
+        reward_rate: global_staking_state.reward_rate,
+        min_stake_amount,
+        max_stake_amount,
+        lock_period,
+        emission_breakpoints: emission_schedule.breakpoints.len() as u8,
+        timestamp: current_time,
+    });
+    
+    msg!("Staking pool {} initialized for mint {}", pool_id, accounts.leash_mint.key());
+    msg!("Reward rate: {} tokens per second", reward_rate);
     msg!("Min stake amount: {}", min_stake_amount);
     msg!("Max stake amount: {}", max_stake_amount);
     msg!("Lock period: {} seconds", lock_period);
+    msg!("Emission schedule breakpoints: {}", emission_schedule.breakpoints.len());
+    msg!("Scheduled rewards funded: {} LEASH", scheduled_rewards);
     
     Ok(())
 }
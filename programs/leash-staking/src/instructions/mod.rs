@@ -1,17 +1,81 @@
 pub mod initialize;
 pub mod stake;
-pub mod unstake;
+pub mod request_unstake;
+pub mod complete_unstake;
+pub mod cancel_unstake;
+pub mod early_unstake;
+pub mod compound_rewards;
+pub mod set_auto_compound;
+pub mod crank_auto_compound;
+pub mod set_keeper_fee;
+pub mod add_reward_stream;
+pub mod set_stream_reward_rate;
+pub mod fund_reward_stream;
+pub mod distribute_stream_fees;
+pub mod claim_stream_rewards;
+pub mod notify_reward_amount;
 pub mod claim_rewards;
 pub mod update_staking_params;
+pub mod queue_param_change;
+pub mod execute_param_change;
+pub mod cancel_param_change;
+pub mod create_multisig;
+pub mod create_multisig_proposal;
+pub mod approve_multisig_proposal;
+pub mod execute_multisig_proposal;
 pub mod distribute_lp_fees;
 pub mod emergency_pause;
 pub mod get_staking_stats;
+pub mod set_guardian;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod renounce_authority;
+pub mod set_fee_distributor;
+pub mod set_rate_manager;
+pub mod set_reward_rate;
+pub mod fund_rewards;
+pub mod compound_lp_fees;
+pub mod get_position_lock;
+pub mod update_lock_tiers;
+pub mod update_penalty_params;
 
 pub use initialize::*;
 pub use stake::*;
-pub use unstake::*;
+pub use request_unstake::*;
+pub use complete_unstake::*;
+pub use cancel_unstake::*;
+pub use early_unstake::*;
+pub use compound_rewards::*;
+pub use set_auto_compound::*;
+pub use crank_auto_compound::*;
+pub use set_keeper_fee::*;
+pub use add_reward_stream::*;
+pub use set_stream_reward_rate::*;
+pub use fund_reward_stream::*;
+pub use distribute_stream_fees::*;
+pub use claim_stream_rewards::*;
+pub use notify_reward_amount::*;
 pub use claim_rewards::*;
 pub use update_staking_params::*;
+pub use queue_param_change::*;
+pub use execute_param_change::*;
+pub use cancel_param_change::*;
+pub use create_multisig::*;
+pub use create_multisig_proposal::*;
+pub use approve_multisig_proposal::*;
+pub use execute_multisig_proposal::*;
 pub use distribute_lp_fees::*;
 pub use emergency_pause::*;
 pub use get_staking_stats::*;
+pub use set_guardian::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use renounce_authority::*;
+pub use set_fee_distributor::*;
+pub use set_rate_manager::*;
+pub use set_reward_rate::*;
+pub use fund_rewards::*;
+pub use compound_lp_fees::*;
+pub use get_position_lock::*;
+pub use update_lock_tiers::*;
+pub use update_penalty_params::*;
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct NotifyRewardAmount<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+        has_one = reward_vault,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Vault holding rewards owed to stakers
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// Token account the rewards are paid from
+    #[account(
+        mut,
+        constraint = funder_leash_account.mint == global_staking_state.leash_mint,
+        constraint = funder_leash_account.owner == authority.key(),
+    )]
+    pub funder_leash_account: Account<'info, TokenAccount>,
+    
+    /// Authority funding the reward period
+    pub authority: Signer<'info>,
+    
+    /// Token program for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> NotifyRewardAmount<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // Check if the funder has sufficient balance
+        require!(
+            self.funder_leash_account.amount >= amount,
+            StakingError::InsufficientBalance
+        );
+        
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<NotifyRewardAmount>, amount: u64, duration: i64) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Validate the funding operation
+    accounts.validate(amount)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Transfer LEASH from funder to the reward vault
+    if amount > 0 {
+        let transfer_ctx = CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.funder_leash_account.to_account_info(),
+                to: accounts.reward_vault.to_account_info(),
+                authority: accounts.authority.to_account_info(),
+            },
+        );
+        
+        token::transfer(transfer_ctx, amount)?;
+    }
+    
+    let global_staking_state = &mut accounts.global_staking_state;
+    global_staking_state.total_rewards_funded = global_staking_state.total_rewards_funded
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    start_reward_period(
+        global_staking_state,
+        &accounts.emission_schedule,
+        &mut accounts.reward_vault,
+        accounts.authority.key(),
+        amount,
+        duration,
+        current_time,
+    )
+}
+
+/// Start a reward period over `amount` already in the reward vault and emit `RewardPeriodStarted`
+pub fn start_reward_period(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    emission_schedule: &EmissionSchedule,
+    reward_vault: &mut Account<TokenAccount>,
+    authority: Pubkey,
+    amount: u64,
+    duration: i64,
+    current_time: i64,
+) -> Result<()> {
+    // Reward periods only apply when no emission schedule is configured
+    require!(
+        !emission_schedule.is_active(),
+        StakingError::EmissionScheduleActive
+    );
+    
+    // Settle the current period, then start the new one with any leftover rolled in
+    global_staking_state.update_rewards(emission_schedule, current_time)?;
+    global_staking_state.notify_reward_amount(amount, duration, current_time)?;
+    
+    // The vault must hold enough to pay the whole period on top of rewards already owed
+    reward_vault.reload()?;
+    let period_rewards = (global_staking_state.reward_rate as u128)
+        .checked_mul(duration as u128)
+        .ok_or(StakingError::MathOverflow)?;
+    require!(
+        period_rewards <= global_staking_state.unowed_rewards(reward_vault.amount) as u128,
+        StakingError::InsufficientRewardFunds
+    );
+    
+    emit!(RewardPeriodStarted {
+        global_staking_state: global_staking_state.key(),
+        authority,
+        amount,
+        duration,
+        reward_rate: global_staking_state.reward_rate,
+        period_finish: global_staking_state.period_finish,
+        total_rewards_funded: global_staking_state.total_rewards_funded,
+        timestamp: current_time,
+    });
+    
+    msg!("Reward period started with {} LEASH over {} seconds", amount, duration);
+    msg!("Reward rate: {}", global_staking_state.reward_rate);
+    msg!("Period finish: {}", global_staking_state.period_finish);
+    
+    Ok(())
+}
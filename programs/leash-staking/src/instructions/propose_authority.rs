@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that can update staking parameters
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let global_staking_state = &mut ctx.accounts.global_staking_state;
+    
+    global_staking_state.propose_authority(new_authority);
+    
+    emit!(AuthorityProposed {
+        global_staking_state: global_staking_state.key(),
+        authority: ctx.accounts.authority.key(),
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Authority transfer proposed to {}", new_authority);
+    
+    Ok(())
+}
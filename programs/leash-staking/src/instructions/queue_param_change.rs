@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct QueueParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Queued change, executable once the timelock elapses
+    #[account(
+        init,
+        payer = authority,
+        space = PendingParamChange::LEN,
+        seeds = [
+            b"pending_param_change",
+            global_staking_state.key().as_ref(),
+            &global_staking_state.param_change_nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub pending_param_change: Account<'info, PendingParamChange>,
+    
+    /// Authority queuing the change
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueParamChange>, change: ParamChange) -> Result<()> {
+    record_param_change(
+        &mut ctx.accounts.global_staking_state,
+        &mut ctx.accounts.pending_param_change,
+        ctx.accounts.authority.key(),
+        change,
+        ctx.bumps.pending_param_change,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Fill in a newly created `PendingParamChange` and emit `ParamChangeQueued`
+pub fn record_param_change(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    pending_param_change: &mut Account<PendingParamChange>,
+    proposer: Pubkey,
+    change: ParamChange,
+    bump: u8,
+    current_time: i64,
+) -> Result<()> {
+    // Reject changes that could never execute; they are checked again at execution
+    global_staking_state.validate_param_change(&change)?;
+    
+    let id = global_staking_state.next_param_change_id()?;
+    let eta = current_time
+        .checked_add(global_staking_state.param_timelock)
+        .ok_or(StakingError::MathOverflow)?;
+    
+    pending_param_change.global_staking_state = global_staking_state.key();
+    pending_param_change.id = id;
+    pending_param_change.proposer = proposer;
+    pending_param_change.change = change;
+    pending_param_change.eta = eta;
+    pending_param_change.bump = bump;
+    
+    emit!(ParamChangeQueued {
+        global_staking_state: global_staking_state.key(),
+        id,
+        proposer: pending_param_change.proposer,
+        change,
+        eta,
+        timestamp: current_time,
+    });
+    
+    msg!("Parameter change {} queued, executable at {}", id, eta);
+    
+    Ok(())
+}
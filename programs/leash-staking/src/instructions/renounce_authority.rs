@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct RenounceAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority giving up control
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RenounceAuthority>) -> Result<()> {
+    apply_renounce_authority(
+        &mut ctx.accounts.global_staking_state,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Renounce the authority and emit `AuthorityRenounced`
+pub fn apply_renounce_authority(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    current_time: i64,
+) -> Result<()> {
+    let old_authority = global_staking_state.authority;
+    global_staking_state.renounce_authority();
+    
+    emit!(AuthorityRenounced {
+        global_staking_state: global_staking_state.key(),
+        old_authority,
+        guardian: global_staking_state.guardian,
+        timestamp: current_time,
+    });
+    
+    msg!("Authority renounced; staking parameters are now frozen");
+    
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetAutoCompound<'info> {
+    /// User's staking position
+    #[account(
+        mut,
+        seeds = [
+            b"user_staking_position",
+            user.key().as_ref(),
+            user_staking_position.global_staking_state.as_ref()
+        ],
+        bump = user_staking_position.bump,
+        constraint = user_staking_position.owner == user.key() @ StakingError::Unauthorized,
+    )]
+    pub user_staking_position: Account<'info, UserStakingPosition>,
+    
+    /// User signing the transaction
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+    let user_position = &mut ctx.accounts.user_staking_position;
+    user_position.auto_compound = enabled;
+    
+    emit!(AutoCompoundToggled {
+        user: ctx.accounts.user.key(),
+        global_staking_state: user_position.global_staking_state,
+        enabled,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Auto-compound {}", if enabled { "enabled" } else { "disabled" });
+    
+    Ok(())
+}
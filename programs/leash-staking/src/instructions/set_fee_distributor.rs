@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetFeeDistributor<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that assigns roles
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetFeeDistributor>, fee_distributor: Pubkey) -> Result<()> {
+    apply_fee_distributor(
+        &mut ctx.accounts.global_staking_state,
+        fee_distributor,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Set the fee distributor and emit `FeeDistributorUpdated`
+pub fn apply_fee_distributor(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    fee_distributor: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    let old_fee_distributor = global_staking_state.fee_distributor;
+    global_staking_state.fee_distributor = fee_distributor;
+    
+    emit!(FeeDistributorUpdated {
+        global_staking_state: global_staking_state.key(),
+        old_fee_distributor,
+        new_fee_distributor: fee_distributor,
+        timestamp: current_time,
+    });
+    
+    msg!("Fee distributor updated: {} -> {}", old_fee_distributor, fee_distributor);
+    
+    Ok(())
+}
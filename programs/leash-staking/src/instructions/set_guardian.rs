@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that can update staking parameters
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    apply_guardian(
+        &mut ctx.accounts.global_staking_state,
+        guardian,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Set the guardian and emit `GuardianUpdated`
+pub fn apply_guardian(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    guardian: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    let old_guardian = global_staking_state.guardian;
+    global_staking_state.guardian = guardian;
+    
+    emit!(GuardianUpdated {
+        global_staking_state: global_staking_state.key(),
+        old_guardian,
+        new_guardian: guardian,
+        timestamp: current_time,
+    });
+    
+    msg!("Guardian updated: {} -> {}", old_guardian, guardian);
+    
+    Ok(())
+}
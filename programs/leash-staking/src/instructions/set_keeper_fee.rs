@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetKeeperFee<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that can update staking parameters
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetKeeperFee>, keeper_fee_bps: u16) -> Result<()> {
+    apply_keeper_fee(
+        &mut ctx.accounts.global_staking_state,
+        keeper_fee_bps,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Set the keeper fee and emit `KeeperFeeUpdated`
+pub fn apply_keeper_fee(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    keeper_fee_bps: u16,
+    current_time: i64,
+) -> Result<()> {
+    // Only queued changes may take effect while a timelock is set
+    require!(
+        global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    GlobalStakingState::validate_keeper_fee(keeper_fee_bps)?;
+    
+    let old_keeper_fee_bps = global_staking_state.keeper_fee_bps;
+    global_staking_state.keeper_fee_bps = keeper_fee_bps;
+    
+    emit!(KeeperFeeUpdated {
+        global_staking_state: global_staking_state.key(),
+        old_keeper_fee_bps,
+        new_keeper_fee_bps: keeper_fee_bps,
+        timestamp: current_time,
+    });
+    
+    msg!("Keeper fee updated: {} -> {} bps", old_keeper_fee_bps, keeper_fee_bps);
+    
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetRateManager<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that assigns roles
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetRateManager>,
+    rate_manager: Pubkey,
+    min_reward_rate: u64,
+    max_reward_rate: u64,
+) -> Result<()> {
+    apply_rate_manager(
+        &mut ctx.accounts.global_staking_state,
+        rate_manager,
+        min_reward_rate,
+        max_reward_rate,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Set the rate manager and its bounds, then emit `RateManagerUpdated`
+pub fn apply_rate_manager(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    rate_manager: Pubkey,
+    min_reward_rate: u64,
+    max_reward_rate: u64,
+    current_time: i64,
+) -> Result<()> {
+    // Only queued changes may take effect while a timelock is set
+    require!(
+        global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    GlobalStakingState::validate_managed_rate_bounds(min_reward_rate, max_reward_rate)?;
+    
+    let old_rate_manager = global_staking_state.rate_manager;
+    global_staking_state.rate_manager = rate_manager;
+    global_staking_state.min_managed_reward_rate = min_reward_rate;
+    global_staking_state.max_managed_reward_rate = max_reward_rate;
+    
+    emit!(RateManagerUpdated {
+        global_staking_state: global_staking_state.key(),
+        old_rate_manager,
+        new_rate_manager: rate_manager,
+        min_reward_rate,
+        max_reward_rate,
+        timestamp: current_time,
+    });
+    
+    msg!("Rate manager updated: {} -> {}", old_rate_manager, rate_manager);
+    msg!("Managed reward rate bounds: {} - {}", min_reward_rate, max_reward_rate);
+    
+    Ok(())
+}
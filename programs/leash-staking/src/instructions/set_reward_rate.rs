@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetRewardRate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = reward_vault,
+        constraint = global_staking_state.is_rate_manager(&rate_manager.key()) @ StakingError::Unauthorized,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Vault holding rewards owed to stakers, which must cover the rest of the period
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    /// Rate manager adjusting the reward rate
+    pub rate_manager: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetRewardRate>, reward_rate: u64) -> Result<()> {
+    // Only queued changes may take effect while a timelock is set
+    require!(
+        ctx.accounts.global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    // The emission schedule sets the rate while it is active
+    require!(
+        !ctx.accounts.emission_schedule.is_active(),
+        StakingError::EmissionScheduleActive
+    );
+    
+    let global_staking_state = &mut ctx.accounts.global_staking_state;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Settle rewards accrued at the old rate before changing it
+    global_staking_state.update_rewards(&ctx.accounts.emission_schedule, current_time)?;
+    
+    let old_reward_rate = global_staking_state.reward_rate;
+    global_staking_state.set_period_reward_rate(reward_rate, ctx.accounts.reward_vault.amount, current_time)?;
+    
+    emit!(RewardRateUpdated {
+        global_staking_state: global_staking_state.key(),
+        rate_manager: ctx.accounts.rate_manager.key(),
+        old_reward_rate,
+        new_reward_rate: reward_rate,
+        timestamp: current_time,
+    });
+    
+    msg!("Reward rate: {} -> {}", old_reward_rate, reward_rate);
+    
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetStreamRewardRate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Authority that can update staking parameters
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetStreamRewardRate>, index: u8, reward_rate: u64) -> Result<()> {
+    apply_stream_reward_rate(
+        &mut ctx.accounts.global_staking_state,
+        &ctx.accounts.emission_schedule,
+        index,
+        reward_rate,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Settle rewards, set a reward stream's rate and emit `RewardStreamRateUpdated`
+pub fn apply_stream_reward_rate(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    emission_schedule: &EmissionSchedule,
+    index: u8,
+    reward_rate: u64,
+    current_time: i64,
+) -> Result<()> {
+    // Stream rates are frozen under a timelock like the pool's other parameters
+    require!(
+        global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    require!(
+        reward_rate <= MAX_REWARD_RATE,
+        StakingError::InvalidRewardRate
+    );
+    
+    // Settle rewards accrued at the old rate before changing it
+    global_staking_state.update_rewards(emission_schedule, current_time)?;
+    
+    let global_staking_state_key = global_staking_state.key();
+    let stream = global_staking_state.reward_stream_mut(index)?;
+    let old_reward_rate = stream.reward_rate;
+    stream.reward_rate = reward_rate;
+    
+    emit!(RewardStreamRateUpdated {
+        global_staking_state: global_staking_state_key,
+        index,
+        old_reward_rate,
+        new_reward_rate: reward_rate,
+        timestamp: current_time,
+    });
+    
+    msg!("Reward stream {} rate: {} -> {}", index, old_reward_rate, reward_rate);
+    
+    Ok(())
+}
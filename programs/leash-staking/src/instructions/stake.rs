@@ -1,20 +1,33 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, MintTo};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, MintTo};
 
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
     #[account(
         mut,
-        seeds = [b"global_staking_state"],
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
         bump = global_staking_state.bump,
         has_one = leash_mint,
         has_one = xleash_mint,
+        has_one = stake_vault,
     )]
     pub global_staking_state: Account<'info, GlobalStakingState>,
     
+    /// Emission schedule for LEASH rewards
+    #[account(
+        seeds = [b"emission_schedule", global_staking_state.key().as_ref()],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
     /// User's staking position
     #[account(
         init_if_needed,
@@ -52,12 +65,17 @@ pub struct Stake<'info> {
     #[account(mut)]
     pub xleash_mint: Account<'info, Mint>,
     
-    /// Treasury account for collecting fees
+    /// Vault holding staked LEASH principal
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// PDA that owns the program's token vaults and the xLEASH mint
+    /// CHECK: Derived from seeds, holds no data
     #[account(
-        mut,
-        constraint = treasury.key() == global_staking_state.treasury,
+        seeds = [b"vault_authority", global_staking_state.key().as_ref()],
+        bump = global_staking_state.vault_authority_bump,
     )]
-    pub treasury: Account<'info, TokenAccount>,
+    pub vault_authority: UncheckedAccount<'info>,
     
     /// User signing the transaction
     #[account(mut)]
@@ -77,74 +95,79 @@ pub struct Stake<'info> {
 }
 
 impl<'info> Stake<'info> {
-    pub fn validate(&self) -> Result<()> {
+    pub fn validate(&self, amount: u64, lock_tier: Option<u8>) -> Result<()> {
         // Check if staking is paused
-        require!(!self.global_staking_state.is_paused, StakingError::StakingPaused);
-        
-        // Validate stake amount
         require!(
-            self.amount >= self.global_staking_state.min_stake_amount,
-            StakingError::InvalidStakeAmount
+            !self.global_staking_state.is_operation_paused(PAUSE_STAKE),
+            StakingError::StakingPaused
         );
         
+        // Validate stake amount
         require!(
-            self.amount <= self.global_staking_state.max_stake_amount,
+            amount >= self.global_staking_state.min_stake_amount,
             StakingError::InvalidStakeAmount
         );
         
+        // Lock tier must be configured
+        self.global_staking_state.lock_terms(lock_tier)?;
+        
         // Check if user has sufficient balance
         require!(
-            self.user_leash_account.amount >= self.amount,
+            self.user_leash_account.amount >= amount,
             StakingError::InsufficientBalance
         );
         
-        // Check if staking pool has capacity
-        let new_total = self.global_staking_state.total_staked
-            .checked_add(self.amount)
-            .ok_or(StakingError::MathOverflow)?;
-        
-        require!(
-            new_total <= self.global_staking_state.max_stake_amount,
-            StakingError::StakingPoolFull
-        );
+        // Check the per-stake maximum and pool capacity
+        self.global_staking_state.check_stake_capacity(amount)?;
         
         Ok(())
     }
 }
 
-pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
-    let accounts = &ctx.accounts;
+pub fn handler(ctx: Context<Stake>, amount: u64, lock_tier: Option<u8>) -> Result<()> {
+    let user_position_bump = ctx.bumps.user_staking_position;
+    let accounts = ctx.accounts;
     
     // Validate the stake operation
-    accounts.validate()?;
+    accounts.validate(amount, lock_tier)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let global_staking_state_key = accounts.global_staking_state.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        global_staking_state_key.as_ref(),
+        &[accounts.global_staking_state.vault_authority_bump],
+    ]];
     
     // Update global rewards before staking
     let global_staking_state = &mut accounts.global_staking_state;
-    global_staking_state.update_rewards();
+    global_staking_state.update_rewards(&accounts.emission_schedule, current_time)?;
     
-    // Calculate xLEASH amount to mint (1:1 ratio for now, can be adjusted)
-    let xleash_amount = amount;
+    // Calculate xLEASH amount to mint at the current exchange rate
+    let xleash_amount = global_staking_state.xleash_for_deposit(amount)?;
     
-    // Transfer LEASH from user to treasury
+    // Transfer LEASH from user to the stake vault
     let transfer_ctx = CpiContext::new(
         accounts.token_program.to_account_info(),
         Transfer {
             from: accounts.user_leash_account.to_account_info(),
-            to: accounts.treasury.to_account_info(),
+            to: accounts.stake_vault.to_account_info(),
             authority: accounts.user.to_account_info(),
         },
     );
     
     token::transfer(transfer_ctx, amount)?;
     
-    // Mint xLEASH to user
-    let mint_ctx = CpiContext::new(
+    // Mint xLEASH to user, signed by the vault authority
+    let mint_ctx = CpiContext::new_with_signer(
         accounts.token_program.to_account_info(),
         MintTo {
             mint: accounts.xleash_mint.to_account_info(),
             to: accounts.user_xleash_account.to_account_info(),
-            authority: accounts.treasury.to_account_info(),
+            authority: accounts.vault_authority.to_account_info(),
         },
+        signer_seeds,
     );
     
     token::mint_to(mint_ctx, xleash_amount)?;
@@ -154,19 +177,29 @@ pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
     
     // If this is a new position, initialize it
     if user_position.owner == Pubkey::default() {
-        let bump = *ctx.bumps.get("user_staking_position").unwrap();
         user_position.initialize(
             accounts.user.key(),
-            accounts.global_staking_state.key(),
-            bump,
+            global_staking_state_key,
+            user_position_bump,
+            current_time,
         );
     }
     
-    // Update rewards before staking
-    user_position.update_rewards(global_staking_state);
+    // Update rewards before staking, then drop boosts on tranches that have unlocked
+    user_position.settle_rewards(global_staking_state, current_time)?;
+    let old_weight = user_position.weighted_stake;
     
-    // Add to staked amount
-    user_position.stake(amount, xleash_amount);
+    // Add a new locked deposit, counting the staker as active if the position was empty
+    let (lock_period, multiplier_bps) = global_staking_state.lock_terms(lock_tier)?;
+    let was_active = user_position.staked_amount > 0;
+    user_position.stake(amount, xleash_amount, lock_period, multiplier_bps, current_time)?;
+    global_staking_state.apply_weight_change(old_weight, user_position.weighted_stake)?;
+    
+    if !was_active && user_position.staked_amount > 0 {
+        global_staking_state.active_stakers = global_staking_state.active_stakers
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
+    }
     
     // Update global state
     global_staking_state.total_staked = global_staking_state.total_staked
@@ -177,8 +210,26 @@ pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
         .checked_add(xleash_amount)
         .ok_or(StakingError::MathOverflow)?;
     
+    // Stake vault must still back all staked principal
+    accounts.stake_vault.reload()?;
+    global_staking_state.check_principal_invariant(accounts.stake_vault.amount)?;
+    
+    emit!(Staked {
+        user: accounts.user.key(),
+        global_staking_state: global_staking_state_key,
+        amount,
+        xleash_amount,
+        lock_period,
+        multiplier_bps,
+        user_staked_amount: user_position.staked_amount,
+        total_staked: global_staking_state.total_staked,
+        total_weighted_stake: global_staking_state.total_weighted_stake,
+        timestamp: current_time,
+    });
+    
     msg!("Successfully staked {} LEASH tokens", amount);
     msg!("Received {} xLEASH tokens", xleash_amount);
+    msg!("Locked for {} seconds at {} bps reward weight", lock_period, multiplier_bps);
     msg!("Total staked: {} LEASH", global_staking_state.total_staked);
     
     Ok(())
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateLockTiers<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that can update staking parameters
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateLockTiers>, lock_tiers: [LockTier; MAX_LOCK_TIERS]) -> Result<()> {
+    apply_lock_tiers(
+        &mut ctx.accounts.global_staking_state,
+        ctx.accounts.authority.key(),
+        lock_tiers,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Replace the lock tiers and emit `LockTiersUpdated`
+pub fn apply_lock_tiers(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    authority: Pubkey,
+    lock_tiers: [LockTier; MAX_LOCK_TIERS],
+    current_time: i64,
+) -> Result<()> {
+    // Only queued changes may take effect while a timelock is set
+    require!(
+        global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    GlobalStakingState::validate_lock_tiers(&lock_tiers)?;
+    
+    // Existing deposits keep the multiplier they locked in
+    let old_lock_tiers = global_staking_state.lock_tiers;
+    global_staking_state.lock_tiers = lock_tiers;
+    
+    emit!(LockTiersUpdated {
+        global_staking_state: global_staking_state.key(),
+        authority,
+        old_lock_tiers,
+        new_lock_tiers: lock_tiers,
+        timestamp: current_time,
+    });
+    
+    for (index, tier) in lock_tiers.iter().enumerate() {
+        msg!("Lock tier {}: {} seconds at {} bps", index, tier.duration, tier.multiplier_bps);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdatePenaltyParams<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that can update staking parameters
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePenaltyParams>,
+    early_exit_penalty_bps: u16,
+    penalty_staker_share_bps: u16,
+) -> Result<()> {
+    apply_penalty_params(
+        &mut ctx.accounts.global_staking_state,
+        ctx.accounts.authority.key(),
+        early_exit_penalty_bps,
+        penalty_staker_share_bps,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Set the early-exit penalty parameters and emit `PenaltyParamsUpdated`
+pub fn apply_penalty_params(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    authority: Pubkey,
+    early_exit_penalty_bps: u16,
+    penalty_staker_share_bps: u16,
+    current_time: i64,
+) -> Result<()> {
+    // Only queued changes may take effect while a timelock is set
+    require!(
+        global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    GlobalStakingState::validate_penalty_params(early_exit_penalty_bps, penalty_staker_share_bps)?;
+    
+    let old_early_exit_penalty_bps = global_staking_state.early_exit_penalty_bps;
+    let old_penalty_staker_share_bps = global_staking_state.penalty_staker_share_bps;
+    
+    global_staking_state.early_exit_penalty_bps = early_exit_penalty_bps;
+    global_staking_state.penalty_staker_share_bps = penalty_staker_share_bps;
+    
+    emit!(PenaltyParamsUpdated {
+        global_staking_state: global_staking_state.key(),
+        authority,
+        old_early_exit_penalty_bps,
+        new_early_exit_penalty_bps: early_exit_penalty_bps,
+        old_penalty_staker_share_bps,
+        new_penalty_staker_share_bps: penalty_staker_share_bps,
+        timestamp: current_time,
+    });
+    
+    msg!("Early-exit penalty: {} -> {} bps", old_early_exit_penalty_bps, early_exit_penalty_bps);
+    msg!("Penalty staker share: {} -> {} bps", old_penalty_staker_share_bps, penalty_staker_share_bps);
+    
+    Ok(())
+}
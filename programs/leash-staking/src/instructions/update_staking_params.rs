@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateStakingParams<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"global_staking_state",
+            global_staking_state.leash_mint.as_ref(),
+            &global_staking_state.pool_id.to_le_bytes(),
+        ],
+        bump = global_staking_state.bump,
+        has_one = authority,
+    )]
+    pub global_staking_state: Account<'info, GlobalStakingState>,
+    
+    /// Authority that can update staking parameters
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateStakingParams>, change: ParamChange) -> Result<()> {
+    // With a timelock, changes go through `queue_param_change`
+    require!(
+        ctx.accounts.global_staking_state.param_timelock == 0,
+        StakingError::ParamChangeTimelocked
+    );
+    
+    apply_param_change(
+        &mut ctx.accounts.global_staking_state,
+        ctx.accounts.authority.key(),
+        &change,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Apply a parameter change and emit `ParamsUpdated`, plus the dedicated event
+/// of any lock tier, penalty or keeper fee it changes
+/// Shared by immediate updates and executed timelocked changes
+pub fn apply_param_change(
+    global_staking_state: &mut Account<GlobalStakingState>,
+    authority: Pubkey,
+    change: &ParamChange,
+    current_time: i64,
+) -> Result<()> {
+    let old_min_stake_amount = global_staking_state.min_stake_amount;
+    let old_max_stake_amount = global_staking_state.max_stake_amount;
+    let old_lock_period = global_staking_state.lock_period;
+    let old_unbonding_period = global_staking_state.unbonding_period;
+    let old_param_timelock = global_staking_state.param_timelock;
+    let old_lock_tiers = global_staking_state.lock_tiers;
+    let old_early_exit_penalty_bps = global_staking_state.early_exit_penalty_bps;
+    let old_penalty_staker_share_bps = global_staking_state.penalty_staker_share_bps;
+    let old_keeper_fee_bps = global_staking_state.keeper_fee_bps;
+    
+    // Apply the same bounds enforced at initialization and by the dedicated setters
+    global_staking_state.apply_param_change(change)?;
+    
+    emit!(ParamsUpdated {
+        global_staking_state: global_staking_state.key(),
+        authority,
+        old_min_stake_amount,
+        new_min_stake_amount: global_staking_state.min_stake_amount,
+        old_max_stake_amount,
+        new_max_stake_amount: global_staking_state.max_stake_amount,
+        old_lock_period,
+        new_lock_period: global_staking_state.lock_period,
+        old_unbonding_period,
+        new_unbonding_period: global_staking_state.unbonding_period,
+        old_param_timelock,
+        new_param_timelock: global_staking_state.param_timelock,
+        timestamp: current_time,
+    });
+    
+    msg!("Staking parameters updated");
+    msg!("Min stake amount: {} -> {}", old_min_stake_amount, global_staking_state.min_stake_amount);
+    msg!("Max stake amount: {} -> {}", old_max_stake_amount, global_staking_state.max_stake_amount);
+    msg!("Lock period: {} -> {} seconds", old_lock_period, global_staking_state.lock_period);
+    msg!("Unbonding period: {} -> {} seconds", old_unbonding_period, global_staking_state.unbonding_period);
+    msg!("Parameter timelock: {} -> {} seconds", old_param_timelock, global_staking_state.param_timelock);
+    
+    if change.lock_tiers.is_some() {
+        emit!(LockTiersUpdated {
+            global_staking_state: global_staking_state.key(),
+            authority,
+            old_lock_tiers,
+            new_lock_tiers: global_staking_state.lock_tiers,
+            timestamp: current_time,
+        });
+        
+        msg!("Lock tiers updated");
+    }
+    
+    if change.early_exit_penalty_bps.is_some() || change.penalty_staker_share_bps.is_some() {
+        emit!(PenaltyParamsUpdated {
+            global_staking_state: global_staking_state.key(),
+            authority,
+            old_early_exit_penalty_bps,
+            new_early_exit_penalty_bps: global_staking_state.early_exit_penalty_bps,
+            old_penalty_staker_share_bps,
+            new_penalty_staker_share_bps: global_staking_state.penalty_staker_share_bps,
+            timestamp: current_time,
+        });
+        
+        msg!("Early-exit penalty: {} -> {} bps", old_early_exit_penalty_bps, global_staking_state.early_exit_penalty_bps);
+        msg!("Penalty staker share: {} -> {} bps", old_penalty_staker_share_bps, global_staking_state.penalty_staker_share_bps);
+    }
+    
+    if change.keeper_fee_bps.is_some() {
+        emit!(KeeperFeeUpdated {
+            global_staking_state: global_staking_state.key(),
+            old_keeper_fee_bps,
+            new_keeper_fee_bps: global_staking_state.keeper_fee_bps,
+            timestamp: current_time,
+        });
+        
+        msg!("Keeper fee: {} -> {} bps", old_keeper_fee_bps, global_staking_state.keeper_fee_bps);
+    }
+    
+    Ok(())
+}
@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod events;
+pub mod math;
 
 use instructions::*;
 use state::*;
@@ -14,34 +15,73 @@ use state::*;
 pub mod leash_staking {
     use super::*;
 
-    /// Initialize the staking program
-    /// Sets up the global staking state and reward parameters
+    /// Create a staking pool
+    /// Pools are keyed by stake mint and `pool_id`, each with its own vaults, receipt mint and optional emission schedule
+    /// A schedule must end at rate zero and is funded in full from the authority's LEASH account
     pub fn initialize(
         ctx: Context<Initialize>,
+        pool_id: u64,
         reward_rate: u64,
         min_stake_amount: u64,
         max_stake_amount: u64,
         lock_period: i64,
+        emission_schedule: Vec<EmissionBreakpoint>,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, reward_rate, min_stake_amount, max_stake_amount, lock_period)
+        instructions::initialize::handler(
+            ctx,
+            pool_id,
+            reward_rate,
+            min_stake_amount,
+            max_stake_amount,
+            lock_period,
+            emission_schedule,
+        )
     }
 
     /// Stake LEASH tokens to receive xLEASH
-    /// Users can stake their LEASH tokens to start earning rewards
+    /// `lock_tier` selects a longer lock with a boosted reward weight; `None` uses the base lock period
     pub fn stake(
         ctx: Context<Stake>,
         amount: u64,
+        lock_tier: Option<u8>,
     ) -> Result<()> {
-        instructions::stake::handler(ctx, amount)
+        instructions::stake::handler(ctx, amount, lock_tier)
     }
 
-    /// Unstake LEASH tokens by burning xLEASH
-    /// Users can unstake their tokens and claim accumulated rewards
-    pub fn unstake(
-        ctx: Context<Unstake>,
+    /// Request an unstake by burning xLEASH
+    /// Burns `amount` xLEASH and queues LEASH at the current exchange rate for the unbonding cooldown
+    pub fn request_unstake(
+        ctx: Context<RequestUnstake>,
         amount: u64,
     ) -> Result<()> {
-        instructions::unstake::handler(ctx, amount)
+        instructions::request_unstake::handler(ctx, amount)
+    }
+
+    /// Withdraw an unbonding request
+    /// Returns the queued LEASH once the cooldown has elapsed
+    pub fn complete_unstake(
+        ctx: Context<CompleteUnstake>,
+        index: u8,
+    ) -> Result<()> {
+        instructions::complete_unstake::handler(ctx, index)
+    }
+
+    /// Cancel an unbonding request
+    /// Re-stakes the queued LEASH as an unlocked deposit and mints xLEASH
+    pub fn cancel_unstake(
+        ctx: Context<CancelUnstake>,
+        index: u8,
+    ) -> Result<()> {
+        instructions::cancel_unstake::handler(ctx, index)
+    }
+
+    /// Unstake before the lock ends by paying a penalty
+    /// The penalty decays linearly to zero at unlock and is split between stakers and the treasury
+    pub fn early_unstake(
+        ctx: Context<EarlyUnstake>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::early_unstake::handler(ctx, amount)
     }
 
     /// Claim accumulated staking rewards
@@ -52,26 +92,175 @@ pub mod leash_staking {
         instructions::claim_rewards::handler(ctx)
     }
 
+    /// Restake pending rewards without withdrawing them
+    /// Callable by the owner, or by anyone if the owner enabled auto-compound
+    pub fn compound_rewards(
+        ctx: Context<CompoundRewards>,
+    ) -> Result<()> {
+        instructions::compound_rewards::handler(ctx)
+    }
+
+    /// Opt in or out of permissionless compounding
+    /// Lets any cranker compound this position's rewards on the owner's behalf
+    pub fn set_auto_compound(
+        ctx: Context<SetAutoCompound>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_auto_compound::handler(ctx, enabled)
+    }
+
+    /// Compound a batch of opted-in positions (permissionless)
+    /// Remaining accounts are (position, owner xLEASH account) pairs; the keeper earns a bounded fee
+    pub fn crank_auto_compound<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankAutoCompound<'info>>,
+    ) -> Result<()> {
+        instructions::crank_auto_compound::handler(ctx)
+    }
+
+    /// Start a reward period (admin only)
+    /// Emits `amount` plus any unemitted leftover evenly until `duration` seconds from now
+    /// The rate cannot be changed any other way while the period runs
+    pub fn notify_reward_amount(
+        ctx: Context<NotifyRewardAmount>,
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::notify_reward_amount::handler(ctx, amount, duration)
+    }
+
+    /// Add a reward token stream (admin only)
+    /// Creates the stream's vault and starts emitting at `reward_rate`; disabled while `param_timelock` is set
+    pub fn add_reward_stream(
+        ctx: Context<AddRewardStream>,
+        reward_rate: u64,
+    ) -> Result<()> {
+        instructions::add_reward_stream::handler(ctx, reward_rate)
+    }
+
+    /// Set a reward stream's emission rate (admin only)
+    /// Settles rewards at the old rate first; disabled while `param_timelock` is set
+    pub fn set_stream_reward_rate(
+        ctx: Context<SetStreamRewardRate>,
+        index: u8,
+        reward_rate: u64,
+    ) -> Result<()> {
+        instructions::set_stream_reward_rate::handler(ctx, index, reward_rate)
+    }
+
+    /// Top up a reward stream's vault
+    /// Anyone can fund emissions for a stream
+    pub fn fund_reward_stream(
+        ctx: Context<FundRewardStream>,
+        index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_reward_stream::handler(ctx, index, amount)
+    }
+
+    /// Distribute fees in a reward stream's native token
+    /// Lets LP fees be paid out without swapping to LEASH
+    pub fn distribute_stream_fees(
+        ctx: Context<DistributeStreamFees>,
+        index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::distribute_stream_fees::handler(ctx, index, amount)
+    }
+
+    /// Set the keeper fee for auto-compounding (admin only)
+    /// Bounded by `MAX_KEEPER_FEE_BPS`; disabled while `param_timelock` is set
+    pub fn set_keeper_fee(
+        ctx: Context<SetKeeperFee>,
+        keeper_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_keeper_fee::handler(ctx, keeper_fee_bps)
+    }
+
+    /// Claim rewards from additional reward streams
+    /// Claims stream `index`, or every active stream when `None`
+    pub fn claim_stream_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimStreamRewards<'info>>,
+        index: Option<u8>,
+    ) -> Result<()> {
+        instructions::claim_stream_rewards::handler(ctx, index)
+    }
+
     /// Update staking parameters (admin only)
-    /// Allows governance to adjust staking parameters
+    /// Applies immediately; once `param_timelock` is set, changes must be queued instead
+    /// Also covers lock tiers, penalties and the keeper fee, so they stay adjustable through
+    /// queued changes while the timelock disables their dedicated setters
     pub fn update_staking_params(
         ctx: Context<UpdateStakingParams>,
-        reward_rate: Option<u64>,
-        min_stake_amount: Option<u64>,
-        max_stake_amount: Option<u64>,
-        lock_period: Option<i64>,
+        change: ParamChange,
     ) -> Result<()> {
-        instructions::update_staking_params::handler(
-            ctx,
-            reward_rate,
-            min_stake_amount,
-            max_stake_amount,
-            lock_period,
-        )
+        instructions::update_staking_params::handler(ctx, change)
+    }
+
+    /// Queue a parameter change behind the timelock (admin only)
+    /// Executable by anyone once `param_timelock` seconds have passed
+    pub fn queue_param_change(
+        ctx: Context<QueueParamChange>,
+        change: ParamChange,
+    ) -> Result<()> {
+        instructions::queue_param_change::handler(ctx, change)
+    }
+
+    /// Execute a queued parameter change (permissionless)
+    /// Fails until the timelock has elapsed
+    pub fn execute_param_change(
+        ctx: Context<ExecuteParamChange>,
+    ) -> Result<()> {
+        instructions::execute_param_change::handler(ctx)
+    }
+
+    /// Cancel a queued parameter change
+    /// Callable by the authority or guardian before it executes
+    pub fn cancel_param_change(
+        ctx: Context<CancelParamChange>,
+    ) -> Result<()> {
+        instructions::cancel_param_change::handler(ctx)
+    }
+
+    /// Create the pool's M-of-N multisig (admin only)
+    /// Hand it the authority with `propose_authority` and an `AcceptAuthority` proposal, or make it the guardian
+    /// As authority it acts through proposals; fee deposits go through a fee distributor wallet it appoints
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_multisig::handler(ctx, members, threshold)
+    }
+
+    /// Propose an admin action for the multisig (members only)
+    /// The proposer's approval is recorded immediately
+    pub fn create_multisig_proposal(
+        ctx: Context<CreateMultisigProposal>,
+        action: MultisigAction,
+    ) -> Result<()> {
+        instructions::create_multisig_proposal::handler(ctx, action)
+    }
+
+    /// Approve a multisig proposal (members only)
+    /// Each member can approve once; the proposal executes after `threshold` approvals
+    pub fn approve_multisig_proposal(
+        ctx: Context<ApproveMultisigProposal>,
+    ) -> Result<()> {
+        instructions::approve_multisig_proposal::handler(ctx)
+    }
+
+    /// Execute a multisig proposal (permissionless)
+    /// Performs the action as the multisig once the approval threshold is met; the caller pays
+    /// rent for any account the action creates and is refunded when it closes. Proposals from before
+    /// a member change cannot execute
+    pub fn execute_multisig_proposal(
+        ctx: Context<ExecuteMultisigProposal>,
+    ) -> Result<()> {
+        instructions::execute_multisig_proposal::handler(ctx)
     }
 
     /// Distribute LP fees to staking rewards
-    /// Called by treasury to distribute collected LP fees
+    /// Called by the authority or fee distributor to distribute collected LP fees
     pub fn distribute_lp_fees(
         ctx: Context<DistributeLpFees>,
         amount: u64,
@@ -79,13 +268,118 @@ pub mod leash_staking {
         instructions::distribute_lp_fees::handler(ctx, amount)
     }
 
+    /// Compound LP fees into the staking pool
+    /// Called by the authority or fee distributor; raises the xLEASH exchange rate instead of paying rewards
+    pub fn compound_lp_fees(
+        ctx: Context<CompoundLpFees>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::compound_lp_fees::handler(ctx, amount)
+    }
+
+    /// Fund the reward vault
+    /// Tops up the LEASH available for reward payouts
+    pub fn fund_rewards(
+        ctx: Context<FundRewards>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_rewards::handler(ctx, amount)
+    }
+
     /// Emergency pause staking operations
-    /// Allows governance to pause staking in emergency situations
+    /// Allows the authority or guardian to pause or resume individual operations
     pub fn emergency_pause(
         ctx: Context<EmergencyPause>,
+        operations: u8,
         pause: bool,
+        reason_code: u16,
+    ) -> Result<()> {
+        instructions::emergency_pause::handler(ctx, operations, pause, reason_code)
+    }
+
+    /// Set the guardian key (admin only)
+    /// The guardian may toggle pause flags alongside the authority
+    pub fn set_guardian(
+        ctx: Context<SetGuardian>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+
+    /// Propose a new authority (admin only)
+    /// The proposed key must accept before it takes over; proposing the default key cancels
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed authority transfer
+    /// Must be signed by the pending authority
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>,
     ) -> Result<()> {
-        instructions::emergency_pause::handler(ctx, pause)
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Renounce the authority (admin only)
+    /// Permanently freezes every admin-only parameter; the guardian can still pause
+    pub fn renounce_authority(
+        ctx: Context<RenounceAuthority>,
+    ) -> Result<()> {
+        instructions::renounce_authority::handler(ctx)
+    }
+
+    /// Set the fee distributor key (admin only)
+    /// The fee distributor may pay in fees alongside the authority, through `distribute_lp_fees`,
+    /// `compound_lp_fees` and `distribute_stream_fees`
+    pub fn set_fee_distributor(
+        ctx: Context<SetFeeDistributor>,
+        fee_distributor: Pubkey,
+    ) -> Result<()> {
+        instructions::set_fee_distributor::handler(ctx, fee_distributor)
+    }
+
+    /// Set the rate manager key and its bounds (admin only)
+    /// The rate manager may set `reward_rate` between `min_reward_rate` and `max_reward_rate`
+    /// Disabled while `param_timelock` is set
+    pub fn set_rate_manager(
+        ctx: Context<SetRateManager>,
+        rate_manager: Pubkey,
+        min_reward_rate: u64,
+        max_reward_rate: u64,
+    ) -> Result<()> {
+        instructions::set_rate_manager::handler(ctx, rate_manager, min_reward_rate, max_reward_rate)
+    }
+
+    /// Change the running reward period's rate (rate manager only)
+    /// Must stay within the bounds set by the authority and what the reward vault can pay until
+    /// `period_finish`; disabled while `param_timelock` is set
+    pub fn set_reward_rate(
+        ctx: Context<SetRewardRate>,
+        reward_rate: u64,
+    ) -> Result<()> {
+        instructions::set_reward_rate::handler(ctx, reward_rate)
+    }
+
+    /// Update the lock tiers (admin only)
+    /// Existing deposits keep the multiplier they were staked with; disabled while `param_timelock` is set
+    pub fn update_lock_tiers(
+        ctx: Context<UpdateLockTiers>,
+        lock_tiers: [LockTier; MAX_LOCK_TIERS],
+    ) -> Result<()> {
+        instructions::update_lock_tiers::handler(ctx, lock_tiers)
+    }
+
+    /// Update the early-exit penalty parameters (admin only)
+    /// Sets the maximum penalty and the share redistributed to stakers; disabled while `param_timelock` is set
+    pub fn update_penalty_params(
+        ctx: Context<UpdatePenaltyParams>,
+        early_exit_penalty_bps: u16,
+        penalty_staker_share_bps: u16,
+    ) -> Result<()> {
+        instructions::update_penalty_params::handler(ctx, early_exit_penalty_bps, penalty_staker_share_bps)
     }
 
     /// Get staking statistics
@@ -95,6 +389,14 @@ pub mod leash_staking {
     ) -> Result<StakingStats> {
         instructions::get_staking_stats::handler(ctx)
     }
+
+    /// Get the lock status of a staking position
+    /// Returns when the position unlocks
+    pub fn get_position_lock(
+        ctx: Context<GetPositionLock>,
+    ) -> Result<PositionLockStatus> {
+        instructions::get_position_lock::handler(ctx)
+    }
 }
 
 /// Error codes for the staking program
@@ -120,4 +422,60 @@ pub enum StakingError {
     InvalidLpFeeDistribution,
     #[msg("Math overflow")]
     MathOverflow,
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[msg("xLEASH mint must be empty and controlled by the vault authority")]
+    InvalidXleashMint,
+    #[msg("Insufficient funds in the reward vault")]
+    InsufficientRewardFunds,
+    #[msg("Stake vault balance does not cover total staked")]
+    VaultInvariantViolated,
+    #[msg("Invalid lock tier")]
+    InvalidLockTier,
+    #[msg("Invalid unbonding period")]
+    InvalidUnbondingPeriod,
+    #[msg("Too many pending unbonding requests")]
+    TooManyUnbondingRequests,
+    #[msg("Unbonding request not found")]
+    InvalidUnbondingRequest,
+    #[msg("Unbonding cooldown has not elapsed")]
+    UnbondingNotComplete,
+    #[msg("Invalid early-exit penalty parameters")]
+    InvalidPenaltyParams,
+    #[msg("Keeper fee exceeds the maximum")]
+    InvalidKeeperFee,
+    #[msg("Crank accounts must be position and xLEASH account pairs")]
+    InvalidCrankAccounts,
+    #[msg("Invalid reward stream")]
+    InvalidRewardStream,
+    #[msg("All reward stream slots are in use")]
+    RewardStreamLimitReached,
+    #[msg("Invalid reward period duration")]
+    InvalidRewardDuration,
+    #[msg("Invalid emission schedule")]
+    InvalidEmissionSchedule,
+    #[msg("Reward rate is set by the emission schedule")]
+    EmissionScheduleActive,
+    #[msg("Invalid parameter timelock")]
+    InvalidParamTimelock,
+    #[msg("Parameter changes must be queued while the timelock is enabled")]
+    ParamChangeTimelocked,
+    #[msg("Parameter change timelock has not elapsed")]
+    ParamChangeNotReady,
+    #[msg("Invalid multisig members or threshold")]
+    InvalidMultisig,
+    #[msg("Signer is not a multisig member")]
+    NotMultisigMember,
+    #[msg("Member has already approved this proposal")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalNotApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("No reward period is running")]
+    RewardPeriodInactive,
+    #[msg("Proposal predates a change to the multisig members")]
+    StaleMultisigProposal,
+    #[msg("Accounts do not match the multisig action")]
+    InvalidMultisigAccounts,
 }
@@ -0,0 +1,163 @@
+use spl_math::uint::U256;
+
+/// Scaling factor for `accumulated_rewards_per_token` (1e18)
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Compute `a * b / c` rounded down, using a 256-bit intermediate
+pub fn mul_div(a: u128, b: u128, c: u128) -> Option<u128> {
+    if c == 0 {
+        return None;
+    }
+    
+    let result = U256::from(a)
+        .checked_mul(U256::from(b))?
+        .checked_div(U256::from(c))?;
+    
+    if result > U256::from(u128::MAX) {
+        return None;
+    }
+    
+    Some(result.as_u128())
+}
+
+/// Scaled reward per staked token for emitting `reward_rate` over `time_diff` seconds
+/// Computes `rate * dt * 1e18 / total_staked`, rounded down
+pub fn emission_per_token(reward_rate: u64, time_diff: u64, total_staked: u64) -> Option<u128> {
+    let emitted = (reward_rate as u128).checked_mul(time_diff as u128)?;
+    amount_per_token(emitted, total_staked)
+}
+
+/// Scaled reward per staked token for distributing `amount` pro rata, rounded down
+pub fn amount_per_token(amount: u128, total_staked: u64) -> Option<u128> {
+    mul_div(amount, REWARD_PRECISION, total_staked as u128)
+}
+
+/// Descale a per-token reward delta into the reward owed on `staked_amount`, rounded down
+pub fn reward_for_stake(staked_amount: u64, rewards_per_token_delta: u128) -> Option<u64> {
+    let reward = mul_div(staked_amount as u128, rewards_per_token_delta, REWARD_PRECISION)?;
+    u64::try_from(reward).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Minimal deterministic generator so the simulations need no extra dependencies
+    struct Lcg(u64);
+    
+    impl Lcg {
+        fn next(&mut self, bound: u64) -> u64 {
+            self.0 = self.0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 33) % bound
+        }
+    }
+    
+    #[derive(Clone, Copy, Default)]
+    struct User {
+        staked: u64,
+        checkpoint: u128,
+        pending: u64,
+    }
+    
+    fn settle(user: &mut User, accumulated: u128) {
+        let earned = reward_for_stake(user.staked, accumulated - user.checkpoint).unwrap();
+        user.pending += earned;
+        user.checkpoint = accumulated;
+    }
+    
+    #[test]
+    fn mul_div_rounds_down_and_rejects_zero_divisor() {
+        assert_eq!(mul_div(10, 10, 3), Some(33));
+        assert_eq!(mul_div(u128::MAX, 2, 2), Some(u128::MAX));
+        assert_eq!(mul_div(u128::MAX, 2, 1), None);
+        assert_eq!(mul_div(1, 1, 0), None);
+    }
+    
+    #[test]
+    fn emission_is_scaled_and_divided_by_total_staked() {
+        // 100 tokens/sec for 10 seconds over 1000 staked = 1 token per staked token
+        let per_token = emission_per_token(100, 10, 1_000).unwrap();
+        assert_eq!(per_token, REWARD_PRECISION);
+        assert_eq!(reward_for_stake(250, per_token), Some(250));
+        assert_eq!(emission_per_token(100, 10, 0), None);
+    }
+    
+    #[test]
+    fn small_amounts_keep_precision() {
+        // 1 token spread over 3 stakers would be lost entirely without scaling
+        let per_token = amount_per_token(1, 3).unwrap();
+        assert!(per_token > 0);
+        assert_eq!(reward_for_stake(3, per_token), Some(0));
+        // A million such distributions still pay out all but rounding dust
+        assert_eq!(reward_for_stake(3, per_token * 1_000_000), Some(999_999));
+    }
+    
+    #[test]
+    fn pending_rewards_never_exceed_emitted() {
+        for seed in 0..200 {
+            let mut rng = Lcg(seed);
+            let reward_rate = rng.next(1_000_000_000) + 1;
+            let mut users = [User::default(); 8];
+            let mut total_staked = 0u64;
+            let mut accumulated = 0u128;
+            let mut emitted = 0u128;
+            
+            for _ in 0..100 {
+                // Accrue emissions for a random interval
+                let time_diff = rng.next(86_400);
+                if total_staked > 0 {
+                    accumulated += emission_per_token(reward_rate, time_diff, total_staked).unwrap();
+                    emitted += reward_rate as u128 * time_diff as u128;
+                }
+                
+                // Random user stakes or unstakes, settling first like the handlers do
+                let user = &mut users[rng.next(8) as usize];
+                settle(user, accumulated);
+                if rng.next(2) == 0 {
+                    let amount = rng.next(1_000_000_000_000) + 1;
+                    user.staked += amount;
+                    total_staked += amount;
+                } else {
+                    let amount = rng.next(user.staked + 1);
+                    user.staked -= amount;
+                    total_staked -= amount;
+                }
+                
+                // Sum of everyone's pending rewards must stay within what was emitted
+                let owed: u128 = users
+                    .iter()
+                    .map(|u| {
+                        let unsettled = reward_for_stake(u.staked, accumulated - u.checkpoint).unwrap();
+                        (u.pending + unsettled) as u128
+                    })
+                    .sum();
+                assert!(owed <= emitted, "seed {}: owed {} > emitted {}", seed, owed, emitted);
+            }
+        }
+    }
+    
+    #[test]
+    fn fee_distribution_never_exceeds_deposited_fees() {
+        for seed in 0..200 {
+            let mut rng = Lcg(seed);
+            let stakes: Vec<u64> = (0..8).map(|_| rng.next(1_000_000_000_000) + 1).collect();
+            let total_staked: u64 = stakes.iter().sum();
+            let mut accumulated = 0u128;
+            let mut deposited = 0u128;
+            
+            for _ in 0..50 {
+                let amount = rng.next(1_000_000_000);
+                accumulated += amount_per_token(amount as u128, total_staked).unwrap();
+                deposited += amount as u128;
+            }
+            
+            let owed: u128 = stakes
+                .iter()
+                .map(|s| reward_for_stake(*s, accumulated).unwrap() as u128)
+                .sum();
+            assert!(owed <= deposited);
+        }
+    }
+}
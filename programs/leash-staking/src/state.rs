@@ -1,5 +1,87 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::StakingError;
+use crate::math;
+
+/// Maximum reward rate (1 token per second across the pool, at 9 decimals)
+pub const MAX_REWARD_RATE: u64 = 1_000_000_000;
+
+/// Minimum lock period (1 day)
+pub const MIN_LOCK_PERIOD: i64 = 86400;
+
+/// Maximum lock period (4 years)
+pub const MAX_LOCK_PERIOD: i64 = 126144000;
+
+/// Basis point denominator for reward multipliers (1x = 10_000)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum reward multiplier for a lock tier (4x)
+pub const MAX_MULTIPLIER_BPS: u16 = 40_000;
+
+/// Number of configurable lock tiers
+pub const MAX_LOCK_TIERS: usize = 4;
+
+/// Default lock tiers: 1 month, 6 months, 1 year and 4 years
+pub const DEFAULT_LOCK_TIERS: [LockTier; MAX_LOCK_TIERS] = [
+    LockTier { duration: 2592000, multiplier_bps: 11_000 },
+    LockTier { duration: 15552000, multiplier_bps: 15_000 },
+    LockTier { duration: 31536000, multiplier_bps: 20_000 },
+    LockTier { duration: 126144000, multiplier_bps: 40_000 },
+];
+
+/// Default cooldown between requesting and completing an unstake (7 days)
+pub const DEFAULT_UNBONDING_PERIOD: i64 = 604800;
+
+/// Maximum unbonding cooldown (30 days)
+pub const MAX_UNBONDING_PERIOD: i64 = 2592000;
+
+/// Default early-exit penalty at the start of a lock (50%)
+pub const DEFAULT_EARLY_EXIT_PENALTY_BPS: u16 = 5_000;
+
+/// Default share of early-exit penalties redistributed to stakers (50%)
+pub const DEFAULT_PENALTY_STAKER_SHARE_BPS: u16 = 5_000;
+
+/// Default fee paid to keepers from auto-compounded rewards (0.1%)
+pub const DEFAULT_KEEPER_FEE_BPS: u16 = 10;
+
+/// Maximum keeper fee (1%)
+pub const MAX_KEEPER_FEE_BPS: u16 = 100;
+
+/// Maximum delay for timelocked parameter changes (30 days)
+pub const MAX_PARAM_TIMELOCK: i64 = 2592000;
+
+/// Maximum length of a reward period (1 year)
+pub const MAX_REWARD_DURATION: i64 = 31536000;
+
+/// Maximum number of breakpoints in an emission schedule
+pub const MAX_EMISSION_BREAKPOINTS: usize = 16;
+
+/// Maximum number of additional reward token streams per pool
+pub const MAX_REWARD_STREAMS: usize = 4;
+
+/// Maximum number of multisig members (approvals are tracked in a `u16` bitmap)
+pub const MAX_MULTISIG_MEMBERS: usize = 10;
+
+/// Maximum number of pending unbonding requests per position
+pub const MAX_UNBONDING_REQUESTS: usize = 8;
+
+/// Precision of the xLEASH exchange rate (LEASH per xLEASH)
+pub const EXCHANGE_RATE_PRECISION: u64 = 1_000_000_000;
+
+/// Pause flag blocking new stakes
+pub const PAUSE_STAKE: u8 = 1 << 0;
+
+/// Pause flag blocking unstakes
+pub const PAUSE_UNSTAKE: u8 = 1 << 1;
+
+/// Pause flag blocking reward claims
+pub const PAUSE_CLAIM: u8 = 1 << 2;
+
+/// Pause flag blocking LP fee distribution
+pub const PAUSE_DISTRIBUTE: u8 = 1 << 3;
+
+/// All pause flags combined
+pub const PAUSE_ALL: u8 = PAUSE_STAKE | PAUSE_UNSTAKE | PAUSE_CLAIM | PAUSE_DISTRIBUTE;
 
 /// Global staking state account
 /// Stores program-wide staking parameters and statistics
@@ -8,16 +90,22 @@ pub struct GlobalStakingState {
     /// Authority that can update staking parameters
     pub authority: Pubkey,
     
-    /// LEASH token mint
+    /// Mint staked in this pool (LEASH or an LP token); also pays the base rewards
     pub leash_mint: Pubkey,
     
     /// xLEASH token mint (staking receipt token)
     pub xleash_mint: Pubkey,
     
-    /// Treasury account for collecting fees
+    /// Treasury token account (in the stake mint) collecting fees
     pub treasury: Pubkey,
     
-    /// Current reward rate (rewards per second per staked token)
+    /// Program-controlled vault holding staked LEASH principal
+    pub stake_vault: Pubkey,
+    
+    /// Program-controlled vault holding rewards owed to stakers
+    pub reward_vault: Pubkey,
+    
+    /// Current reward rate (rewards per second, shared pro rata by all stakers)
     pub reward_rate: u64,
     
     /// Minimum stake amount
@@ -29,12 +117,21 @@ pub struct GlobalStakingState {
     /// Lock period for staked tokens (in seconds)
     pub lock_period: i64,
     
-    /// Total amount of LEASH currently staked
+    /// Optional longer locks with boosted reward weight
+    pub lock_tiers: [LockTier; MAX_LOCK_TIERS],
+    
+    /// Total amount of LEASH currently staked, including compounded LP fees
     pub total_staked: u64,
     
+    /// Sum of every position's lock-boosted reward weight
+    pub total_weighted_stake: u64,
+    
     /// Total amount of xLEASH minted
     pub total_xleash_minted: u64,
     
+    /// Number of positions with a non-zero stake
+    pub active_stakers: u32,
+    
     /// Accumulated rewards per token (scaled by 1e18)
     pub accumulated_rewards_per_token: u128,
     
@@ -44,210 +141,3001 @@ pub struct GlobalStakingState {
     /// Total rewards distributed
     pub total_rewards_distributed: u64,
     
+    /// Total rewards deposited into the reward vault via `fund_rewards`
+    pub total_rewards_funded: u64,
+    
     /// LP fees collected for distribution
     pub lp_fees_collected: u64,
     
-    /// Whether staking is paused
+    /// LP fees, early-exit penalties and forfeited boost rewards received but not yet folded into rewards per token
+    /// (deposited while nothing was staked, or rounding remainder)
+    pub undistributed_lp_fees: u64,
+    
+    /// Whether any operation is paused
     pub is_paused: bool,
     
     /// Bump seed for PDA derivation
     pub bump: u8,
     
-    /// Reserved space for future upgrades
-    pub reserved: [u8; 64],
+    /// Bump seed for the vault authority PDA
+    pub vault_authority_bump: u8,
+    
+    /// Per-operation pause flags (see `PAUSE_*`)
+    pub pause_flags: u8,
+    
+    /// Guardian key allowed to toggle pause flags alongside the authority
+    pub guardian: Pubkey,
+    
+    /// Cooldown between requesting and completing an unstake (in seconds)
+    pub unbonding_period: i64,
+    
+    /// LEASH queued for withdrawal, held in the stake vault but no longer staked
+    pub total_unbonding: u64,
+    
+    /// Early-exit penalty at the start of a lock, decaying linearly to zero at unlock (in basis points)
+    pub early_exit_penalty_bps: u16,
+    
+    /// Share of early-exit penalties redistributed to stakers, the rest goes to the treasury (in basis points)
+    pub penalty_staker_share_bps: u16,
+    
+    /// Fee paid to keepers from auto-compounded rewards (in basis points)
+    pub keeper_fee_bps: u16,
+    
+    /// End of the current reward period; LEASH emissions stop accruing after this
+    pub period_finish: i64,
+    
+    /// Additional reward token streams, paid alongside LEASH emissions
+    pub reward_streams: [RewardStream; MAX_REWARD_STREAMS],
+    
+    /// Identifier distinguishing pools that stake the same mint
+    pub pool_id: u64,
+    
+    /// Proposed new authority, waiting to accept (default key when none)
+    pub pending_authority: Pubkey,
+    
+    /// Key allowed to pay in LP and stream fees alongside the authority
+    pub fee_distributor: Pubkey,
+    
+    /// Key allowed to change the running period's `reward_rate` within the managed bounds
+    pub rate_manager: Pubkey,
+    
+    /// Lowest reward rate the rate manager may set
+    pub min_managed_reward_rate: u64,
+    
+    /// Highest reward rate the rate manager may set
+    pub max_managed_reward_rate: u64,
+    
+    /// Delay before queued parameter changes can execute (in seconds); zero applies changes immediately
+    pub param_timelock: i64,
+    
+    /// Number of parameter changes queued so far, used to derive `PendingParamChange` addresses
+    pub param_change_nonce: u64,
+    
+    /// LEASH in the reward vault already owed to stakers: emitted or received rewards not yet paid out
+    /// Includes per-token rounding dust, so it may slightly overstate what positions can claim
+    pub rewards_owed: u64,
+    
+    /// Reserved space for future upgrades; new fields are carved from here
+    pub reserved: [u8; 56],
 }
 
 impl GlobalStakingState {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 16 + 8 + 8 + 8 + 1 + 1 + 64;
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + LockTier::LEN * MAX_LOCK_TIERS + 8 + 8 + 8 + 4 + 16 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1 + 32 + 8 + 8 + 2 + 2 + 2 + 8 + RewardStream::LEN * MAX_REWARD_STREAMS + 8 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 56;
     
     /// Initialize a new global staking state
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
+        pool_id: u64,
         authority: Pubkey,
         leash_mint: Pubkey,
         xleash_mint: Pubkey,
         treasury: Pubkey,
+        stake_vault: Pubkey,
+        reward_vault: Pubkey,
         reward_rate: u64,
         min_stake_amount: u64,
         max_stake_amount: u64,
         lock_period: i64,
         bump: u8,
+        vault_authority_bump: u8,
+        current_time: i64,
     ) {
+        self.pool_id = pool_id;
         self.authority = authority;
         self.leash_mint = leash_mint;
         self.xleash_mint = xleash_mint;
         self.treasury = treasury;
+        self.stake_vault = stake_vault;
+        self.reward_vault = reward_vault;
         self.reward_rate = reward_rate;
         self.min_stake_amount = min_stake_amount;
         self.max_stake_amount = max_stake_amount;
         self.lock_period = lock_period;
+        self.lock_tiers = DEFAULT_LOCK_TIERS;
         self.total_staked = 0;
+        self.total_weighted_stake = 0;
         self.total_xleash_minted = 0;
+        self.active_stakers = 0;
         self.accumulated_rewards_per_token = 0;
-        self.last_update_time = Clock::get().unwrap().unix_timestamp;
+        self.last_update_time = current_time;
         self.total_rewards_distributed = 0;
+        self.total_rewards_funded = 0;
         self.lp_fees_collected = 0;
+        self.undistributed_lp_fees = 0;
         self.is_paused = false;
         self.bump = bump;
-        self.reserved = [0; 64];
+        self.vault_authority_bump = vault_authority_bump;
+        self.pause_flags = 0;
+        self.guardian = Pubkey::default();
+        self.pending_authority = Pubkey::default();
+        self.fee_distributor = Pubkey::default();
+        self.rate_manager = Pubkey::default();
+        self.min_managed_reward_rate = 0;
+        self.max_managed_reward_rate = 0;
+        self.param_timelock = 0;
+        self.param_change_nonce = 0;
+        self.unbonding_period = DEFAULT_UNBONDING_PERIOD;
+        self.total_unbonding = 0;
+        self.early_exit_penalty_bps = DEFAULT_EARLY_EXIT_PENALTY_BPS;
+        self.penalty_staker_share_bps = DEFAULT_PENALTY_STAKER_SHARE_BPS;
+        self.keeper_fee_bps = DEFAULT_KEEPER_FEE_BPS;
+        // Emissions start with the first `notify_reward_amount`
+        self.period_finish = current_time;
+        self.reward_streams = [RewardStream::default(); MAX_REWARD_STREAMS];
+        self.rewards_owed = 0;
+        self.reserved = [0; 56];
+    }
+    
+    /// Validate staking parameters against program-wide bounds
+    /// Shared by `initialize` and `update_staking_params`
+    pub fn validate_params(
+        reward_rate: u64,
+        min_stake_amount: u64,
+        max_stake_amount: u64,
+        lock_period: i64,
+    ) -> Result<()> {
+        // Validate reward rate is reasonable (not too high to prevent overflow)
+        require!(
+            reward_rate <= MAX_REWARD_RATE,
+            StakingError::InvalidRewardRate
+        );
+        
+        // Validate stake amounts
+        require!(
+            min_stake_amount > 0,
+            StakingError::InvalidStakeAmount
+        );
+        
+        require!(
+            max_stake_amount >= min_stake_amount,
+            StakingError::InvalidStakeAmount
+        );
+        
+        // Validate lock period (minimum 1 day, maximum 4 years)
+        require!(
+            (MIN_LOCK_PERIOD..=MAX_LOCK_PERIOD).contains(&lock_period),
+            StakingError::InvalidStakeAmount
+        );
+        
+        Ok(())
+    }
+    
+    /// Validate the unbonding cooldown against program-wide bounds
+    pub fn validate_unbonding_period(unbonding_period: i64) -> Result<()> {
+        require!(
+            (0..=MAX_UNBONDING_PERIOD).contains(&unbonding_period),
+            StakingError::InvalidUnbondingPeriod
+        );
+        
+        Ok(())
+    }
+    
+    /// Validate the delay applied to queued parameter changes
+    pub fn validate_param_timelock(param_timelock: i64) -> Result<()> {
+        require!(
+            (0..=MAX_PARAM_TIMELOCK).contains(&param_timelock),
+            StakingError::InvalidParamTimelock
+        );
+        
+        Ok(())
+    }
+    
+    /// Validate a parameter change against the current parameters
+    pub fn validate_param_change(&self, change: &ParamChange) -> Result<()> {
+        Self::validate_params(
+            self.reward_rate,
+            change.min_stake_amount.unwrap_or(self.min_stake_amount),
+            change.max_stake_amount.unwrap_or(self.max_stake_amount),
+            change.lock_period.unwrap_or(self.lock_period),
+        )?;
+        Self::validate_unbonding_period(change.unbonding_period.unwrap_or(self.unbonding_period))?;
+        Self::validate_param_timelock(change.param_timelock.unwrap_or(self.param_timelock))?;
+        Self::validate_lock_tiers(&change.lock_tiers.unwrap_or(self.lock_tiers))?;
+        Self::validate_penalty_params(
+            change.early_exit_penalty_bps.unwrap_or(self.early_exit_penalty_bps),
+            change.penalty_staker_share_bps.unwrap_or(self.penalty_staker_share_bps),
+        )?;
+        Self::validate_keeper_fee(change.keeper_fee_bps.unwrap_or(self.keeper_fee_bps))
+    }
+    
+    /// Validate and apply a parameter change
+    pub fn apply_param_change(&mut self, change: &ParamChange) -> Result<()> {
+        self.validate_param_change(change)?;
+        
+        self.min_stake_amount = change.min_stake_amount.unwrap_or(self.min_stake_amount);
+        self.max_stake_amount = change.max_stake_amount.unwrap_or(self.max_stake_amount);
+        self.lock_period = change.lock_period.unwrap_or(self.lock_period);
+        self.unbonding_period = change.unbonding_period.unwrap_or(self.unbonding_period);
+        self.param_timelock = change.param_timelock.unwrap_or(self.param_timelock);
+        self.lock_tiers = change.lock_tiers.unwrap_or(self.lock_tiers);
+        self.early_exit_penalty_bps = change.early_exit_penalty_bps.unwrap_or(self.early_exit_penalty_bps);
+        self.penalty_staker_share_bps = change.penalty_staker_share_bps.unwrap_or(self.penalty_staker_share_bps);
+        self.keeper_fee_bps = change.keeper_fee_bps.unwrap_or(self.keeper_fee_bps);
+        
+        Ok(())
+    }
+    
+    /// Take the next parameter change id
+    pub fn next_param_change_id(&mut self) -> Result<u64> {
+        let id = self.param_change_nonce;
+        self.param_change_nonce = id.checked_add(1).ok_or(StakingError::MathOverflow)?;
+        Ok(id)
+    }
+    
+    /// Validate early-exit penalty parameters (both in basis points)
+    pub fn validate_penalty_params(early_exit_penalty_bps: u16, penalty_staker_share_bps: u16) -> Result<()> {
+        require!(
+            early_exit_penalty_bps as u64 <= BPS_DENOMINATOR
+                && penalty_staker_share_bps as u64 <= BPS_DENOMINATOR,
+            StakingError::InvalidPenaltyParams
+        );
+        
+        Ok(())
+    }
+    
+    /// Split an early-exit penalty into the stakers' share and the treasury's share
+    pub fn split_penalty(&self, penalty: u64) -> Result<(u64, u64)> {
+        let staker_share = math::mul_div(
+            penalty as u128,
+            self.penalty_staker_share_bps as u128,
+            BPS_DENOMINATOR as u128,
+        )
+        .ok_or(StakingError::MathOverflow)? as u64;
+        
+        Ok((staker_share, penalty - staker_share))
+    }
+    
+    /// Validate the keeper's cut of auto-compounded rewards
+    pub fn validate_keeper_fee(keeper_fee_bps: u16) -> Result<()> {
+        require!(
+            keeper_fee_bps <= MAX_KEEPER_FEE_BPS,
+            StakingError::InvalidKeeperFee
+        );
+        
+        Ok(())
     }
     
-    /// Update accumulated rewards per token
-    pub fn update_rewards(&mut self) {
-        let current_time = Clock::get().unwrap().unix_timestamp;
-        let time_diff = current_time - self.last_update_time;
+    /// Keeper's cut of `amount` compounded rewards, rounded down
+    pub fn keeper_fee(&self, amount: u64) -> Result<u64> {
+        let fee = math::mul_div(
+            amount as u128,
+            self.keeper_fee_bps as u128,
+            BPS_DENOMINATOR as u128,
+        )
+        .ok_or(StakingError::MathOverflow)?;
         
-        if time_diff > 0 && self.total_staked > 0 {
-            let rewards = (self.reward_rate as u128)
-                .checked_mul(time_diff as u128)
-                .unwrap_or(0);
+        Ok(fee as u64)
+    }
+    
+    /// Validate lock tiers: increasing durations within the lock bounds,
+    /// multipliers between 1x and `MAX_MULTIPLIER_BPS` that never decrease
+    pub fn validate_lock_tiers(lock_tiers: &[LockTier; MAX_LOCK_TIERS]) -> Result<()> {
+        for (index, tier) in lock_tiers.iter().enumerate() {
+            require!(
+                (MIN_LOCK_PERIOD..=MAX_LOCK_PERIOD).contains(&tier.duration),
+                StakingError::InvalidLockTier
+            );
             
-            self.accumulated_rewards_per_token = self.accumulated_rewards_per_token
-                .checked_add(rewards)
-                .unwrap_or(0);
+            require!(
+                tier.multiplier_bps as u64 >= BPS_DENOMINATOR && tier.multiplier_bps <= MAX_MULTIPLIER_BPS,
+                StakingError::InvalidLockTier
+            );
+            
+            if index > 0 {
+                let previous = &lock_tiers[index - 1];
+                require!(
+                    tier.duration > previous.duration && tier.multiplier_bps >= previous.multiplier_bps,
+                    StakingError::InvalidLockTier
+                );
+            }
         }
         
-        self.last_update_time = current_time;
+        Ok(())
     }
     
-    /// Add LP fees to the collection
-    pub fn add_lp_fees(&mut self, amount: u64) {
-        self.lp_fees_collected = self.lp_fees_collected.checked_add(amount).unwrap_or(0);
+    /// Lock period and reward multiplier for a stake
+    /// `None` uses the base `lock_period` at 1x
+    pub fn lock_terms(&self, lock_tier: Option<u8>) -> Result<(i64, u16)> {
+        match lock_tier {
+            None => Ok((self.lock_period, BPS_DENOMINATOR as u16)),
+            Some(index) => {
+                let tier = self.lock_tiers
+                    .get(index as usize)
+                    .ok_or(StakingError::InvalidLockTier)?;
+                Ok((tier.duration, tier.multiplier_bps))
+            }
+        }
     }
-}
-
-/// User staking position account
-/// Stores individual user staking data and rewards
-#[account]
-pub struct UserStakingPosition {
-    /// Owner of this staking position
-    pub owner: Pubkey,
     
-    /// Global staking state this position belongs to
-    pub global_staking_state: Pubkey,
+    /// Replace a position's reward weight in the pool total
+    pub fn apply_weight_change(&mut self, old_weight: u64, new_weight: u64) -> Result<()> {
+        self.total_weighted_stake = self.total_weighted_stake
+            .checked_sub(old_weight)
+            .ok_or(StakingError::MathOverflow)?
+            .checked_add(new_weight)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        Ok(())
+    }
     
-    /// Amount of LEASH staked
-    pub staked_amount: u64,
+    /// Check that `amount` fits the per-stake maximum and the pool's capacity
+    pub fn check_stake_capacity(&self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.max_stake_amount,
+            StakingError::InvalidStakeAmount
+        );
+        
+        let new_total = self.total_staked
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        require!(
+            new_total <= self.max_stake_amount,
+            StakingError::StakingPoolFull
+        );
+        
+        Ok(())
+    }
     
-    /// Amount of xLEASH held
-    pub xleash_amount: u64,
+    /// Whether the given operation (a `PAUSE_*` flag) is paused
+    pub fn is_operation_paused(&self, operation: u8) -> bool {
+        self.pause_flags & operation != 0
+    }
     
-    /// Accumulated rewards per token when user last staked/unstaked
-    pub user_rewards_per_token: u128,
+    /// Validate that pause flags name at least one known operation
+    pub fn validate_pause_flags(operations: u8) -> Result<()> {
+        require!(
+            operations != 0 && operations & !PAUSE_ALL == 0,
+            StakingError::InvalidPauseFlags
+        );
+        
+        Ok(())
+    }
     
-    /// Pending rewards for this user
-    pub pending_rewards: u64,
+    /// Pause or resume the given operations
+    pub fn set_paused(&mut self, operations: u8, pause: bool) {
+        if pause {
+            self.pause_flags |= operations;
+        } else {
+            self.pause_flags &= !operations;
+        }
+        self.is_paused = self.pause_flags != 0;
+    }
     
-    /// Timestamp when user first staked
-    pub staking_start_time: i64,
+    /// Nominate a new authority, which takes over once it accepts
+    /// Proposing the default key cancels a pending transfer
+    pub fn propose_authority(&mut self, new_authority: Pubkey) {
+        self.pending_authority = new_authority;
+    }
     
-    /// Last time user claimed rewards
-    pub last_claim_time: i64,
+    /// Hand the authority to the pending authority
+    pub fn accept_authority(&mut self, signer: &Pubkey) -> Result<()> {
+        require!(
+            self.pending_authority != Pubkey::default() && *signer == self.pending_authority,
+            StakingError::Unauthorized
+        );
+        
+        self.authority = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+        
+        Ok(())
+    }
     
-    /// Whether this position is locked
-    pub is_locked: bool,
+    /// Give up the authority permanently
+    /// No key can sign as the default authority, so admin-only parameters are frozen;
+    /// the rate manager is removed with it so the reward rate is frozen too
+    pub fn renounce_authority(&mut self) {
+        self.authority = Pubkey::default();
+        self.pending_authority = Pubkey::default();
+        self.rate_manager = Pubkey::default();
+    }
     
-    /// Lock end time (if locked)
-    pub lock_end_time: i64,
+    /// Whether the authority has been renounced
+    pub fn is_renounced(&self) -> bool {
+        self.authority == Pubkey::default()
+    }
     
-    /// Bump seed for PDA derivation
-    pub bump: u8,
+    /// Whether the key may pay in LP or stream fees
+    pub fn can_distribute_fees(&self, key: &Pubkey) -> bool {
+        (!self.is_renounced() && *key == self.authority)
+            || (self.fee_distributor != Pubkey::default() && *key == self.fee_distributor)
+    }
     
-    /// Reserved space for future upgrades
-    pub reserved: [u8; 32],
-}
-
-impl UserStakingPosition {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 16 + 8 + 8 + 8 + 1 + 8 + 1 + 32;
+    /// Whether the key may set the reward rate within the managed bounds
+    pub fn is_rate_manager(&self, key: &Pubkey) -> bool {
+        self.rate_manager != Pubkey::default() && *key == self.rate_manager
+    }
     
-    /// Initialize a new user staking position
-    pub fn initialize(
-        &mut self,
-        owner: Pubkey,
-        global_staking_state: Pubkey,
-        bump: u8,
-    ) {
-        self.owner = owner;
-        self.global_staking_state = global_staking_state;
-        self.staked_amount = 0;
-        self.xleash_amount = 0;
-        self.user_rewards_per_token = 0;
-        self.pending_rewards = 0;
-        self.staking_start_time = Clock::get().unwrap().unix_timestamp;
-        self.last_claim_time = Clock::get().unwrap().unix_timestamp;
-        self.is_locked = false;
-        self.lock_end_time = 0;
-        self.bump = bump;
-        self.reserved = [0; 32];
+    /// Validate the bounds the rate manager may move the reward rate within
+    pub fn validate_managed_rate_bounds(min_reward_rate: u64, max_reward_rate: u64) -> Result<()> {
+        require!(
+            min_reward_rate <= max_reward_rate && max_reward_rate <= MAX_REWARD_RATE,
+            StakingError::InvalidRewardRate
+        );
+        
+        Ok(())
     }
     
-    /// Calculate pending rewards for this user
-    pub fn calculate_pending_rewards(&self, global_state: &GlobalStakingState) -> u64 {
-        let rewards_per_token_diff = global_state.accumulated_rewards_per_token
-            .checked_sub(self.user_rewards_per_token)
-            .unwrap_or(0);
+    /// Check a reward rate requested by the rate manager
+    pub fn check_managed_reward_rate(&self, reward_rate: u64) -> Result<()> {
+        require!(
+            reward_rate >= self.min_managed_reward_rate && reward_rate <= self.max_managed_reward_rate,
+            StakingError::InvalidRewardRate
+        );
         
-        let pending = (self.staked_amount as u128)
-            .checked_mul(rewards_per_token_diff)
-            .unwrap_or(0);
+        Ok(())
+    }
+    
+    /// Change the running reward period's rate, keeping its `period_finish`
+    /// The rest of the period at the new rate must be covered by unowed rewards in the vault
+    /// Rewards must already be settled up to `current_time`
+    pub fn set_period_reward_rate(&mut self, reward_rate: u64, reward_vault_balance: u64, current_time: i64) -> Result<()> {
+        self.check_managed_reward_rate(reward_rate)?;
+        
+        require!(
+            self.is_reward_period_active(current_time),
+            StakingError::RewardPeriodInactive
+        );
+        
+        let remaining_rewards = (reward_rate as u128)
+            .checked_mul((self.period_finish - current_time) as u128)
+            .ok_or(StakingError::MathOverflow)?;
+        require!(
+            remaining_rewards <= self.unowed_rewards(reward_vault_balance) as u128,
+            StakingError::InsufficientRewardFunds
+        );
+        
+        self.reward_rate = reward_rate;
         
-        pending.checked_add(self.pending_rewards as u128).unwrap_or(0) as u64
+        Ok(())
     }
     
-    /// Update user rewards
-    pub fn update_rewards(&mut self, global_state: &GlobalStakingState) {
-        let pending = self.calculate_pending_rewards(global_state);
-        self.pending_rewards = pending;
-        self.user_rewards_per_token = global_state.accumulated_rewards_per_token;
+    /// Whether the key may cancel a queued parameter change
+    pub fn can_cancel_param_change(&self, key: &Pubkey) -> bool {
+        self.can_pause(key)
     }
     
-    /// Stake tokens
-    pub fn stake(&mut self, amount: u64, xleash_amount: u64) {
-        self.staked_amount = self.staked_amount.checked_add(amount).unwrap_or(0);
-        self.xleash_amount = self.xleash_amount.checked_add(xleash_amount).unwrap_or(0);
-        self.staking_start_time = Clock::get().unwrap().unix_timestamp;
+    /// Whether the key may toggle pause flags
+    pub fn can_pause(&self, key: &Pubkey) -> bool {
+        (!self.is_renounced() && *key == self.authority)
+            || (self.guardian != Pubkey::default() && *key == self.guardian)
     }
     
-    /// Unstake tokens
-    pub fn unstake(&mut self, amount: u64, xleash_amount: u64) {
-        self.staked_amount = self.staked_amount.checked_sub(amount).unwrap_or(0);
-        self.xleash_amount = self.xleash_amount.checked_sub(xleash_amount).unwrap_or(0);
+    /// LEASH emitted between the last update and `current_time`
+    /// Follows the emission schedule when one is configured, otherwise `reward_rate` until `period_finish`
+    pub fn emitted_since_update(&self, emission_schedule: &EmissionSchedule, current_time: i64) -> Result<u128> {
+        if emission_schedule.is_active() {
+            return emission_schedule
+                .emitted_between(self.last_update_time, current_time)
+                .ok_or_else(|| error!(StakingError::MathOverflow));
+        }
+        
+        let time_diff = current_time.min(self.period_finish) - self.last_update_time;
+        if time_diff <= 0 {
+            return Ok(0);
+        }
+        
+        Ok((self.reward_rate as u128)
+            .checked_mul(time_diff as u128)
+            .ok_or(StakingError::MathOverflow)?)
     }
     
-    /// Claim rewards
-    pub fn claim_rewards(&mut self, amount: u64) {
-        self.pending_rewards = self.pending_rewards.checked_sub(amount).unwrap_or(0);
-        self.last_claim_time = Clock::get().unwrap().unix_timestamp;
+    /// Project accumulated rewards per token to the given time without mutating state
+    pub fn rewards_per_token_at(&self, emission_schedule: &EmissionSchedule, current_time: i64) -> Result<u128> {
+        if current_time <= self.last_update_time || self.total_weighted_stake == 0 {
+            return Ok(self.accumulated_rewards_per_token);
+        }
+        
+        let emitted = self.emitted_since_update(emission_schedule, current_time)?;
+        let rewards = math::amount_per_token(emitted, self.total_weighted_stake)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        Ok(self.accumulated_rewards_per_token
+            .checked_add(rewards)
+            .ok_or(StakingError::MathOverflow)?)
     }
-}
-
-/// Staking statistics for queries
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct StakingStats {
-    /// Total amount staked
-    pub total_staked: u64,
     
-    /// Total xLEASH minted
-    pub total_xleash_minted: u64,
+    /// Update accumulated rewards per token for LEASH and every reward stream
+    pub fn update_rewards(&mut self, emission_schedule: &EmissionSchedule, current_time: i64) -> Result<()> {
+        let accumulated_rewards_per_token = self.rewards_per_token_at(emission_schedule, current_time)?;
+        
+        // Only emissions that reach a staker become owed
+        let mut rewards_owed = self.rewards_owed;
+        if current_time > self.last_update_time && self.total_weighted_stake > 0 {
+            let emitted = u64::try_from(self.emitted_since_update(emission_schedule, current_time)?)
+                .map_err(|_| error!(StakingError::MathOverflow))?;
+            rewards_owed = rewards_owed
+                .checked_add(emitted)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+        
+        let time_diff = (current_time - self.last_update_time).max(0) as u64;
+        let mut stream_rewards_per_token = [0u128; MAX_REWARD_STREAMS];
+        for (index, stream) in self.reward_streams.iter().enumerate() {
+            stream_rewards_per_token[index] = stream.rewards_per_token_after(time_diff, self.total_weighted_stake)?;
+        }
+        
+        self.accumulated_rewards_per_token = accumulated_rewards_per_token;
+        self.rewards_owed = rewards_owed;
+        if emission_schedule.is_active() {
+            self.reward_rate = emission_schedule.rate_at(current_time);
+        }
+        for (stream, rewards_per_token) in self.reward_streams.iter_mut().zip(stream_rewards_per_token) {
+            stream.accumulated_rewards_per_token = rewards_per_token;
+        }
+        self.last_update_time = current_time;
+        
+        Ok(())
+    }
     
-    /// Current reward rate
-    pub reward_rate: u64,
+    /// Start a new reward period emitting `amount` plus any unemitted leftover over `duration`
+    /// Rewards must already be settled up to `current_time`
+    pub fn notify_reward_amount(&mut self, amount: u64, duration: i64, current_time: i64) -> Result<()> {
+        require!(
+            duration > 0 && duration <= MAX_REWARD_DURATION,
+            StakingError::InvalidRewardDuration
+        );
+        
+        // Roll whatever the current period has not emitted yet into the new one
+        let leftover = if current_time < self.period_finish {
+            ((self.period_finish - current_time) as u128)
+                .checked_mul(self.reward_rate as u128)
+                .ok_or(StakingError::MathOverflow)?
+        } else {
+            0
+        };
+        
+        let reward_rate = (amount as u128)
+            .checked_add(leftover)
+            .ok_or(StakingError::MathOverflow)?
+            / duration as u128;
+        
+        require!(
+            reward_rate > 0 && reward_rate <= MAX_REWARD_RATE as u128,
+            StakingError::InvalidRewardRate
+        );
+        
+        let period_finish = current_time
+            .checked_add(duration)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        self.reward_rate = reward_rate as u64;
+        self.last_update_time = current_time;
+        self.period_finish = period_finish;
+        
+        Ok(())
+    }
     
-    /// Accumulated rewards per token
-    pub accumulated_rewards_per_token: u128,
+    /// Register a new reward stream in the first free slot, returning its index
+    pub fn add_reward_stream(&mut self, mint: Pubkey, vault: Pubkey, reward_rate: u64) -> Result<u8> {
+        require!(
+            mint != self.leash_mint && !self.reward_streams.iter().any(|stream| stream.mint == mint),
+            StakingError::InvalidRewardStream
+        );
+        
+        let index = self.reward_streams
+            .iter()
+            .position(|stream| !stream.is_active())
+            .ok_or(StakingError::RewardStreamLimitReached)?;
+        
+        self.reward_streams[index] = RewardStream {
+            mint,
+            vault,
+            reward_rate,
+            ..RewardStream::default()
+        };
+        
+        Ok(index as u8)
+    }
     
-    /// Total rewards distributed
-    pub total_rewards_distributed: u64,
+    /// Active reward stream at `index`
+    pub fn reward_stream_mut(&mut self, index: u8) -> Result<&mut RewardStream> {
+        self.reward_streams
+            .get_mut(index as usize)
+            .filter(|stream| stream.is_active())
+            .ok_or_else(|| error!(StakingError::InvalidRewardStream))
+    }
     
-    /// LP fees collected
-    pub lp_fees_collected: u64,
+    /// xLEASH to mint for a deposit, rounded down in favor of the vault
+    /// Mints 1:1 while the pool is empty
+    pub fn xleash_for_deposit(&self, amount: u64) -> Result<u64> {
+        if self.total_staked == 0 || self.total_xleash_minted == 0 {
+            return Ok(amount);
+        }
+        
+        let xleash_amount = (amount as u128)
+            .checked_mul(self.total_xleash_minted as u128)
+            .ok_or(StakingError::MathOverflow)?
+            / self.total_staked as u128;
+        
+        require!(xleash_amount > 0, StakingError::InvalidStakeAmount);
+        
+        u64::try_from(xleash_amount).map_err(|_| error!(StakingError::MathOverflow))
+    }
     
-    /// Number of active stakers
-    pub active_stakers: u32,
+    /// LEASH returned for burning xLEASH, rounded down in favor of the vault
+    pub fn leash_for_redemption(&self, xleash_amount: u64) -> Result<u64> {
+        require!(
+            xleash_amount <= self.total_xleash_minted,
+            StakingError::InsufficientBalance
+        );
+        
+        let leash_amount = (xleash_amount as u128)
+            .checked_mul(self.total_staked as u128)
+            .ok_or(StakingError::MathOverflow)?
+            / self.total_xleash_minted as u128;
+        
+        u64::try_from(leash_amount).map_err(|_| error!(StakingError::MathOverflow))
+    }
     
-    /// Whether staking is paused
-    pub is_paused: bool,
+    /// Current xLEASH exchange rate, scaled by `EXCHANGE_RATE_PRECISION`
+    pub fn exchange_rate(&self) -> Result<u64> {
+        if self.total_staked == 0 || self.total_xleash_minted == 0 {
+            return Ok(EXCHANGE_RATE_PRECISION);
+        }
+        
+        let rate = (self.total_staked as u128) * (EXCHANGE_RATE_PRECISION as u128)
+            / self.total_xleash_minted as u128;
+        
+        u64::try_from(rate).map_err(|_| error!(StakingError::MathOverflow))
+    }
     
-    /// Current timestamp
-    pub current_time: i64,
+    /// Compound LP fees into the pool, raising the value of every xLEASH
+    pub fn compound_lp_fees(&mut self, amount: u64) -> Result<()> {
+        // Compounding into an empty pool would gift the fees to the next staker
+        require!(
+            self.total_xleash_minted > 0,
+            StakingError::InvalidLpFeeDistribution
+        );
+        
+        let total_staked = self.total_staked
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let lp_fees_collected = self.lp_fees_collected
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        self.total_staked = total_staked;
+        self.lp_fees_collected = lp_fees_collected;
+        
+        Ok(())
+    }
+    
+    /// Check that the stake vault still backs all staked and unbonding principal
+    pub fn check_principal_invariant(&self, stake_vault_balance: u64) -> Result<()> {
+        let required = self.total_staked
+            .checked_add(self.total_unbonding)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        require!(
+            stake_vault_balance >= required,
+            StakingError::VaultInvariantViolated
+        );
+        
+        Ok(())
+    }
+    
+    /// Add LP fees to the collection and fold them into rewards per token
+    /// Fees that cannot be distributed yet are carried forward
+    pub fn add_lp_fees(&mut self, amount: u64) -> Result<()> {
+        let lp_fees_collected = self.lp_fees_collected
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        self.receive_rewards(amount)?;
+        self.lp_fees_collected = lp_fees_collected;
+        
+        Ok(())
+    }
+    
+    /// Fold `amount` of reward tokens newly deposited into the reward vault into rewards per token,
+    /// recording them as owed to stakers
+    pub fn receive_rewards(&mut self, amount: u64) -> Result<()> {
+        let rewards_owed = self.rewards_owed
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        self.distribute_rewards(amount)?;
+        self.rewards_owed = rewards_owed;
+        
+        Ok(())
+    }
+    
+    /// Record `amount` of owed rewards paid out of the reward vault
+    pub fn record_rewards_paid(&mut self, amount: u64) -> Result<()> {
+        self.total_rewards_distributed = self.total_rewards_distributed
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        self.rewards_owed = self.rewards_owed
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Reward vault balance not yet owed to stakers, available to fund new emissions
+    pub fn unowed_rewards(&self, reward_vault_balance: u64) -> u64 {
+        reward_vault_balance.saturating_sub(self.rewards_owed)
+    }
+    
+    /// Whether a reward period is emitting at `reward_rate`
+    pub fn is_reward_period_active(&self, current_time: i64) -> bool {
+        current_time < self.period_finish
+    }
+    
+    /// Fold `amount` of reward tokens into rewards per token
+    /// Amounts that cannot be distributed yet are carried forward
+    pub fn distribute_rewards(&mut self, amount: u64) -> Result<()> {
+        let mut undistributed_lp_fees = self.undistributed_lp_fees
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let mut accumulated_rewards_per_token = self.accumulated_rewards_per_token;
+        
+        if self.total_weighted_stake > 0 {
+            let rewards_per_token = math::amount_per_token(undistributed_lp_fees as u128, self.total_weighted_stake)
+                .ok_or(StakingError::MathOverflow)?;
+            
+            accumulated_rewards_per_token = accumulated_rewards_per_token
+                .checked_add(rewards_per_token)
+                .ok_or(StakingError::MathOverflow)?;
+            
+            // Keep the rounding remainder for the next distribution
+            let distributed = math::reward_for_stake(self.total_weighted_stake, rewards_per_token)
+                .ok_or(StakingError::MathOverflow)?;
+            undistributed_lp_fees = undistributed_lp_fees
+                .checked_sub(distributed)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+        
+        self.undistributed_lp_fees = undistributed_lp_fees;
+        self.accumulated_rewards_per_token = accumulated_rewards_per_token;
+        
+        Ok(())
+    }
+}
+
+/// Maximum number of deposit tranches tracked per position
+pub const MAX_DEPOSIT_TRANCHES: usize = 16;
+
+/// A single deposit within a staking position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct DepositTranche {
+    /// Principal remaining in this deposit
+    pub amount: u64,
+    
+    /// Time the deposit was made
+    pub start_time: i64,
+    
+    /// Time the deposit unlocks
+    pub unlock_time: i64,
+    
+    /// Reward multiplier while locked, in basis points
+    pub multiplier_bps: u16,
+}
+
+impl DepositTranche {
+    pub const LEN: usize = 8 + 8 + 8 + 2;
+    
+    /// Reward weight of this deposit
+    pub fn weight(&self) -> Result<u64> {
+        let weight = (self.amount as u128)
+            .checked_mul(self.multiplier_bps as u128)
+            .ok_or(StakingError::MathOverflow)?
+            / BPS_DENOMINATOR as u128;
+        
+        u64::try_from(weight).map_err(|_| error!(StakingError::MathOverflow))
+    }
+}
+
+/// A lock duration and the reward multiplier it earns
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct LockTier {
+    /// Lock duration in seconds
+    pub duration: i64,
+    
+    /// Reward multiplier in basis points
+    pub multiplier_bps: u16,
+}
+
+impl LockTier {
+    pub const LEN: usize = 8 + 2;
+}
+
+/// LEASH emission rate from `start_time` until the next breakpoint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct EmissionBreakpoint {
+    /// Time this rate takes effect
+    pub start_time: i64,
+    
+    /// Emission rate (tokens per second across the pool)
+    pub reward_rate: u64,
+}
+
+impl EmissionBreakpoint {
+    pub const LEN: usize = 8 + 8;
+}
+
+/// Piecewise LEASH emission curve for a pool
+/// While it holds breakpoints it replaces `reward_rate` and reward periods
+#[account]
+pub struct EmissionSchedule {
+    /// Global staking state this schedule belongs to
+    pub global_staking_state: Pubkey,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Breakpoints in increasing `start_time` order
+    pub breakpoints: Vec<EmissionBreakpoint>,
+}
+
+impl EmissionSchedule {
+    pub const LEN: usize = 8 + 32 + 1 + 4 + EmissionBreakpoint::LEN * MAX_EMISSION_BREAKPOINTS;
+    
+    /// Validate breakpoints: bounded count, strictly increasing start times, rates within bounds,
+    /// and a final breakpoint at rate zero so the total emission is finite
+    pub fn validate_breakpoints(breakpoints: &[EmissionBreakpoint]) -> Result<()> {
+        require!(
+            breakpoints.len() <= MAX_EMISSION_BREAKPOINTS,
+            StakingError::InvalidEmissionSchedule
+        );
+        
+        require!(
+            breakpoints.last().map_or(0, |last| last.reward_rate) == 0,
+            StakingError::InvalidEmissionSchedule
+        );
+        
+        for (index, breakpoint) in breakpoints.iter().enumerate() {
+            require!(
+                breakpoint.reward_rate <= MAX_REWARD_RATE,
+                StakingError::InvalidEmissionSchedule
+            );
+            
+            if index > 0 {
+                require!(
+                    breakpoint.start_time > breakpoints[index - 1].start_time,
+                    StakingError::InvalidEmissionSchedule
+                );
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Whether the schedule drives emissions
+    pub fn is_active(&self) -> bool {
+        !self.breakpoints.is_empty()
+    }
+    
+    /// Emission rate in effect at `time` (zero before the first breakpoint)
+    pub fn rate_at(&self, time: i64) -> u64 {
+        self.breakpoints
+            .iter()
+            .rev()
+            .find(|breakpoint| breakpoint.start_time <= time)
+            .map(|breakpoint| breakpoint.reward_rate)
+            .unwrap_or(0)
+    }
+    
+    /// Total still to be emitted from `from` until the schedule ends
+    pub fn remaining_emission(&self, from: i64) -> Option<u128> {
+        match self.breakpoints.last() {
+            Some(last) => self.emitted_between(from, last.start_time),
+            None => Some(0),
+        }
+    }
+    
+    /// Total emitted between `from` and `to`, integrating across breakpoints
+    pub fn emitted_between(&self, from: i64, to: i64) -> Option<u128> {
+        let mut emitted = 0u128;
+        
+        for (index, breakpoint) in self.breakpoints.iter().enumerate() {
+            let segment_end = self.breakpoints
+                .get(index + 1)
+                .map(|next| next.start_time)
+                .unwrap_or(i64::MAX);
+            
+            let start = from.max(breakpoint.start_time);
+            let end = to.min(segment_end);
+            if end > start {
+                let segment = (breakpoint.reward_rate as u128).checked_mul((end - start) as u128)?;
+                emitted = emitted.checked_add(segment)?;
+            }
+        }
+        
+        Some(emitted)
+    }
+}
+
+/// A set of staking parameter updates; `None` leaves a parameter unchanged
+/// The reward rate is not among them: reward periods and the rate manager set it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParamChange {
+    pub min_stake_amount: Option<u64>,
+    pub max_stake_amount: Option<u64>,
+    pub lock_period: Option<i64>,
+    pub unbonding_period: Option<i64>,
+    pub param_timelock: Option<i64>,
+    pub lock_tiers: Option<[LockTier; MAX_LOCK_TIERS]>,
+    pub early_exit_penalty_bps: Option<u16>,
+    pub penalty_staker_share_bps: Option<u16>,
+    pub keeper_fee_bps: Option<u16>,
+}
+
+impl ParamChange {
+    pub const LEN: usize = 9 + 9 + 9 + 9 + 9 + 1 + LockTier::LEN * MAX_LOCK_TIERS + 3 + 3 + 3;
+}
+
+/// A parameter change queued behind the timelock
+/// Anyone can execute it once `eta` has passed; the authority or guardian can cancel it before then
+#[account]
+pub struct PendingParamChange {
+    /// Global staking state the change applies to
+    pub global_staking_state: Pubkey,
+    
+    /// Sequence number from the pool's `param_change_nonce`
+    pub id: u64,
+    
+    /// Account that paid the rent to queue the change, refunded when it closes
+    pub proposer: Pubkey,
+    
+    /// Parameters to update
+    pub change: ParamChange,
+    
+    /// Earliest time the change can be executed
+    pub eta: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PendingParamChange {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + ParamChange::LEN + 8 + 1;
+    
+    /// Whether the timelock has elapsed
+    pub fn is_ready(&self, current_time: i64) -> bool {
+        current_time >= self.eta
+    }
+}
+
+/// Admin action a multisig proposal performs once approved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum MultisigAction {
+    /// Update staking parameters, as `update_staking_params`
+    UpdateStakingParams(ParamChange),
+    
+    /// Pause or resume operations, as `emergency_pause`
+    SetPaused { operations: u8, pause: bool, reason_code: u16 },
+    
+    /// Propose a new authority, as `propose_authority`
+    ProposeAuthority(Pubkey),
+    
+    /// Accept an authority transfer proposed to the multisig, as `accept_authority`
+    AcceptAuthority,
+    
+    /// Give up the authority for good, as `renounce_authority`
+    RenounceAuthority,
+    
+    /// Queue a timelocked parameter change, as `queue_param_change`
+    QueueParamChange(ParamChange),
+    
+    /// Start a reward period over LEASH already deposited with `fund_rewards`, as `notify_reward_amount`
+    NotifyRewardAmount { amount: u64, duration: i64 },
+    
+    /// Set the guardian, as `set_guardian`
+    SetGuardian(Pubkey),
+    
+    /// Set the fee distributor, as `set_fee_distributor`
+    SetFeeDistributor(Pubkey),
+    
+    /// Set the rate manager and its bounds, as `set_rate_manager`
+    SetRateManager { rate_manager: Pubkey, min_reward_rate: u64, max_reward_rate: u64 },
+    
+    /// Replace the lock tiers, as `update_lock_tiers`
+    UpdateLockTiers([LockTier; MAX_LOCK_TIERS]),
+    
+    /// Update the early-exit penalty, as `update_penalty_params`
+    UpdatePenaltyParams { early_exit_penalty_bps: u16, penalty_staker_share_bps: u16 },
+    
+    /// Set the keeper fee, as `set_keeper_fee`
+    SetKeeperFee(u16),
+    
+    /// Add a reward stream, as `add_reward_stream`
+    AddRewardStream { reward_mint: Pubkey, reward_rate: u64 },
+    
+    /// Set a reward stream's rate, as `set_stream_reward_rate`
+    SetStreamRewardRate { index: u8, reward_rate: u64 },
+    
+    /// Add a member to the multisig
+    AddMember(Pubkey),
+    
+    /// Remove a member from the multisig
+    RemoveMember(Pubkey),
+    
+    /// Change the number of approvals required
+    ChangeThreshold(u8),
+    
+    /// Cancel the queued parameter change with this id, as `cancel_param_change`
+    CancelParamChange(u64),
+}
+
+impl MultisigAction {
+    /// `ParamChange` is the largest payload
+    pub const LEN: usize = 1 + ParamChange::LEN;
+    
+    /// Mint of the stream an `AddRewardStream` action adds (default key for other actions)
+    pub fn reward_mint(&self) -> Pubkey {
+        match self {
+            MultisigAction::AddRewardStream { reward_mint, .. } => *reward_mint,
+            _ => Pubkey::default(),
+        }
+    }
+}
+
+/// M-of-N multisig that can act as a pool's authority or guardian
+/// Its address signs for admin actions once a proposal reaches the threshold
+#[account]
+pub struct Multisig {
+    /// Global staking state this multisig administers
+    pub global_staking_state: Pubkey,
+    
+    /// Keys allowed to propose and approve
+    pub members: Vec<Pubkey>,
+    
+    /// Approvals required to execute a proposal
+    pub threshold: u8,
+    
+    /// Number of proposals created so far, used to derive proposal addresses
+    pub proposal_count: u64,
+    
+    /// Bumped whenever members or threshold change, invalidating open proposals
+    pub member_set_version: u32,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_MULTISIG_MEMBERS + 1 + 8 + 4 + 1;
+    
+    /// Validate the member set and threshold
+    pub fn validate_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty()
+                && members.len() <= MAX_MULTISIG_MEMBERS
+                && threshold > 0
+                && threshold as usize <= members.len(),
+            StakingError::InvalidMultisig
+        );
+        
+        for (index, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..index].contains(member),
+                StakingError::InvalidMultisig
+            );
+        }
+        
+        Ok(())
+    }
+    
+    /// Position of `key` in the member list
+    pub fn member_index(&self, key: &Pubkey) -> Result<usize> {
+        self.members
+            .iter()
+            .position(|member| member == key)
+            .ok_or_else(|| StakingError::NotMultisigMember.into())
+    }
+    
+    /// Take the next proposal id
+    pub fn next_proposal_id(&mut self) -> Result<u64> {
+        let id = self.proposal_count;
+        self.proposal_count = id.checked_add(1).ok_or(StakingError::MathOverflow)?;
+        Ok(id)
+    }
+    
+    /// Replace the members and threshold, invalidating open proposals
+    /// Approval bitmaps index into the member list, so they cannot carry over
+    pub fn set_members(&mut self, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        Self::validate_members(&members, threshold)?;
+        
+        self.member_set_version = self.member_set_version
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
+        self.members = members;
+        self.threshold = threshold;
+        
+        Ok(())
+    }
+    
+    /// Add a member, keeping the threshold
+    pub fn add_member(&mut self, member: Pubkey) -> Result<()> {
+        let mut members = self.members.clone();
+        members.push(member);
+        self.set_members(members, self.threshold)
+    }
+    
+    /// Remove a member, keeping the threshold
+    pub fn remove_member(&mut self, member: Pubkey) -> Result<()> {
+        let index = self.member_index(&member)?;
+        let mut members = self.members.clone();
+        members.remove(index);
+        self.set_members(members, self.threshold)
+    }
+    
+    /// Change the number of approvals required
+    pub fn change_threshold(&mut self, threshold: u8) -> Result<()> {
+        self.set_members(self.members.clone(), threshold)
+    }
+}
+
+/// An admin action awaiting multisig approvals
+#[account]
+pub struct MultisigProposal {
+    /// Multisig this proposal belongs to
+    pub multisig: Pubkey,
+    
+    /// Sequence number from the multisig's `proposal_count`
+    pub id: u64,
+    
+    /// Member that created the proposal
+    pub proposer: Pubkey,
+    
+    /// Action to perform once approved
+    pub action: MultisigAction,
+    
+    /// Multisig `member_set_version` the proposal was created under
+    pub member_set_version: u32,
+    
+    /// Bitmap of approving members, indexed by position in `Multisig::members`
+    pub approvals: u16,
+    
+    /// Whether the action has been performed
+    pub executed: bool,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + MultisigAction::LEN + 4 + 2 + 1 + 1;
+    
+    /// Record the approval of the member at `member_index`
+    pub fn approve(&mut self, member_index: usize) -> Result<()> {
+        require!(!self.executed, StakingError::ProposalAlreadyExecuted);
+        
+        let bit = 1u16 << member_index;
+        require!(self.approvals & bit == 0, StakingError::ProposalAlreadyApproved);
+        self.approvals |= bit;
+        
+        Ok(())
+    }
+    
+    /// Number of members that have approved
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+    
+    /// Whether enough members have approved to execute
+    pub fn is_approved(&self, threshold: u8) -> bool {
+        self.approval_count() >= threshold as u32
+    }
+}
+
+/// An additional reward token paid pro rata to reward weight
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RewardStream {
+    /// Reward token mint (default while the slot is unused)
+    pub mint: Pubkey,
+    
+    /// Vault holding this stream's rewards
+    pub vault: Pubkey,
+    
+    /// Emission rate (tokens per second across the pool)
+    pub reward_rate: u64,
+    
+    /// Accumulated rewards per unit of reward weight, scaled by `math::REWARD_PRECISION`
+    pub accumulated_rewards_per_token: u128,
+    
+    /// Total rewards deposited into the vault
+    pub total_funded: u64,
+    
+    /// Total rewards claimed from the vault
+    pub total_distributed: u64,
+    
+    /// Fees received but not yet folded into rewards per token
+    pub undistributed: u64,
+}
+
+impl RewardStream {
+    pub const LEN: usize = 32 + 32 + 8 + 16 + 8 + 8 + 8;
+    
+    /// Whether this slot holds a stream
+    pub fn is_active(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+    
+    /// Accumulated rewards per token after emitting for `time_diff` seconds
+    pub fn rewards_per_token_after(&self, time_diff: u64, total_weighted_stake: u64) -> Result<u128> {
+        if !self.is_active() || time_diff == 0 || total_weighted_stake == 0 {
+            return Ok(self.accumulated_rewards_per_token);
+        }
+        
+        let rewards = math::emission_per_token(self.reward_rate, time_diff, total_weighted_stake)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        Ok(self.accumulated_rewards_per_token
+            .checked_add(rewards)
+            .ok_or(StakingError::MathOverflow)?)
+    }
+    
+    /// Fold `amount` of this stream's token into rewards per token
+    /// Amounts that cannot be distributed yet are carried forward
+    pub fn distribute(&mut self, amount: u64, total_weighted_stake: u64) -> Result<()> {
+        let mut undistributed = self.undistributed
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let mut accumulated_rewards_per_token = self.accumulated_rewards_per_token;
+        
+        if total_weighted_stake > 0 {
+            let rewards_per_token = math::amount_per_token(undistributed as u128, total_weighted_stake)
+                .ok_or(StakingError::MathOverflow)?;
+            
+            accumulated_rewards_per_token = accumulated_rewards_per_token
+                .checked_add(rewards_per_token)
+                .ok_or(StakingError::MathOverflow)?;
+            
+            // Keep the rounding remainder for the next distribution
+            let distributed = math::reward_for_stake(total_weighted_stake, rewards_per_token)
+                .ok_or(StakingError::MathOverflow)?;
+            undistributed = undistributed
+                .checked_sub(distributed)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+        
+        self.undistributed = undistributed;
+        self.accumulated_rewards_per_token = accumulated_rewards_per_token;
+        
+        Ok(())
+    }
+}
+
+/// A position's checkpoint in one reward stream
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct StreamReward {
+    /// Stream's accumulated rewards per token when last settled
+    pub rewards_per_token: u128,
+    
+    /// Rewards settled but not yet claimed
+    pub pending_rewards: u64,
+}
+
+impl StreamReward {
+    pub const LEN: usize = 16 + 8;
+}
+
+/// LEASH queued for withdrawal after the unbonding cooldown
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct UnbondingRequest {
+    /// LEASH to be withdrawn
+    pub amount: u64,
+    
+    /// Time the unstake was requested
+    pub request_time: i64,
+    
+    /// Time the LEASH can be withdrawn
+    pub unlock_time: i64,
+}
+
+impl UnbondingRequest {
+    pub const LEN: usize = 8 + 8 + 8;
+}
+
+/// User staking position account
+/// Stores individual user staking data and rewards
+#[account]
+pub struct UserStakingPosition {
+    /// Owner of this staking position
+    pub owner: Pubkey,
+    
+    /// Global staking state this position belongs to
+    pub global_staking_state: Pubkey,
+    
+    /// Amount of LEASH staked (principal deposited, used as reward weight)
+    pub staked_amount: u64,
+    
+    /// Amount of xLEASH minted to this position
+    pub xleash_amount: u64,
+    
+    /// Lock-boosted reward weight (sum of tranche weights)
+    pub weighted_stake: u64,
+    
+    /// Accumulated rewards per token when user last staked/unstaked
+    pub user_rewards_per_token: u128,
+    
+    /// Pending rewards for this user
+    pub pending_rewards: u64,
+    
+    /// Timestamp when user first staked
+    pub staking_start_time: i64,
+    
+    /// Last time user claimed rewards
+    pub last_claim_time: i64,
+    
+    /// Whether this position holds any deposit tranches
+    pub is_locked: bool,
+    
+    /// Time at which the last deposit tranche unlocks
+    pub lock_end_time: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Whether anyone may compound this position's rewards on the owner's behalf
+    pub auto_compound: bool,
+    
+    /// Last time rewards were settled with `settle_rewards`
+    pub last_reward_update: i64,
+    
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 23],
+    
+    /// Checkpoints in each of the pool's reward streams, by stream index
+    pub stream_rewards: [StreamReward; MAX_REWARD_STREAMS],
+    
+    /// Individual deposits, oldest first
+    pub deposits: Vec<DepositTranche>,
+    
+    /// Pending unbonding requests, oldest first
+    pub unbonding: Vec<UnbondingRequest>,
+}
+
+impl UserStakingPosition {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 16 + 8 + 8 + 8 + 1 + 8 + 1 + 1 + 8 + 23 + StreamReward::LEN * MAX_REWARD_STREAMS + 4 + DepositTranche::LEN * MAX_DEPOSIT_TRANCHES + 4 + UnbondingRequest::LEN * MAX_UNBONDING_REQUESTS;
+    
+    /// Initialize a new user staking position
+    pub fn initialize(
+        &mut self,
+        owner: Pubkey,
+        global_staking_state: Pubkey,
+        bump: u8,
+        current_time: i64,
+    ) {
+        self.owner = owner;
+        self.global_staking_state = global_staking_state;
+        self.staked_amount = 0;
+        self.xleash_amount = 0;
+        self.weighted_stake = 0;
+        self.user_rewards_per_token = 0;
+        self.pending_rewards = 0;
+        self.staking_start_time = current_time;
+        self.last_claim_time = current_time;
+        self.is_locked = false;
+        self.lock_end_time = 0;
+        self.bump = bump;
+        self.auto_compound = false;
+        self.last_reward_update = current_time;
+        self.reserved = [0; 23];
+        self.stream_rewards = [StreamReward::default(); MAX_REWARD_STREAMS];
+        self.deposits = Vec::new();
+        self.unbonding = Vec::new();
+    }
+    
+    /// Calculate pending rewards for this user
+    pub fn calculate_pending_rewards(&self, global_state: &GlobalStakingState) -> Result<u64> {
+        let rewards_per_token_diff = global_state.accumulated_rewards_per_token
+            .checked_sub(self.user_rewards_per_token)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let pending = math::reward_for_stake(self.weighted_stake, rewards_per_token_diff)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        Ok(pending
+            .checked_add(self.pending_rewards)
+            .ok_or(StakingError::MathOverflow)?)
+    }
+    
+    /// Calculate pending rewards in the reward stream at `index`
+    pub fn calculate_pending_stream_rewards(&self, global_state: &GlobalStakingState, index: usize) -> Result<u64> {
+        let stream = &global_state.reward_streams[index];
+        let checkpoint = &self.stream_rewards[index];
+        
+        let rewards_per_token_diff = stream.accumulated_rewards_per_token
+            .checked_sub(checkpoint.rewards_per_token)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let pending = math::reward_for_stake(self.weighted_stake, rewards_per_token_diff)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        Ok(pending
+            .checked_add(checkpoint.pending_rewards)
+            .ok_or(StakingError::MathOverflow)?)
+    }
+    
+    /// Update user rewards in LEASH and every reward stream
+    pub fn update_rewards(&mut self, global_state: &GlobalStakingState) -> Result<()> {
+        let pending = self.calculate_pending_rewards(global_state)?;
+        
+        let mut stream_pending = [0u64; MAX_REWARD_STREAMS];
+        for (index, pending_rewards) in stream_pending.iter_mut().enumerate() {
+            *pending_rewards = self.calculate_pending_stream_rewards(global_state, index)?;
+        }
+        
+        self.pending_rewards = pending;
+        self.user_rewards_per_token = global_state.accumulated_rewards_per_token;
+        for (index, checkpoint) in self.stream_rewards.iter_mut().enumerate() {
+            checkpoint.pending_rewards = stream_pending[index];
+            checkpoint.rewards_per_token = global_state.reward_streams[index].accumulated_rewards_per_token;
+        }
+        
+        Ok(())
+    }
+    
+    /// Reward weight the boosts of unlocked tranches carried since the last settlement,
+    /// prorated to the part of that interval after each tranche unlocked
+    /// The split is by time, so it is exact while the reward rate per weight is constant
+    fn expired_boost_weight(&self, current_time: i64) -> Result<u64> {
+        let elapsed = current_time.saturating_sub(self.last_reward_update);
+        if elapsed <= 0 {
+            return Ok(0);
+        }
+        
+        let mut expired_weight: u128 = 0;
+        for tranche in self.deposits.iter() {
+            if current_time <= tranche.unlock_time || tranche.multiplier_bps as u64 <= BPS_DENOMINATOR {
+                continue;
+            }
+            
+            let boost_weight = tranche.weight()?
+                .checked_sub(tranche.amount)
+                .ok_or(StakingError::MathOverflow)?;
+            let after_unlock = current_time - tranche.unlock_time.max(self.last_reward_update);
+            
+            let prorated = math::mul_div(boost_weight as u128, after_unlock as u128, elapsed as u128)
+                .ok_or(StakingError::MathOverflow)?;
+            expired_weight = expired_weight
+                .checked_add(prorated)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+        
+        u64::try_from(expired_weight).map_err(|_| error!(StakingError::MathOverflow))
+    }
+    
+    /// Settle rewards and drop the boosts of unlocked tranches
+    /// Boosts only earn until their tranche unlocks; whatever they accrued after that
+    /// is taken back and redistributed to all stakers at their current weight
+    pub fn settle_rewards(&mut self, global_state: &mut GlobalStakingState, current_time: i64) -> Result<()> {
+        let expired_weight = self.expired_boost_weight(current_time)?;
+        
+        let rewards_per_token_diff = global_state.accumulated_rewards_per_token
+            .checked_sub(self.user_rewards_per_token)
+            .ok_or(StakingError::MathOverflow)?;
+        let forfeited = math::reward_for_stake(expired_weight, rewards_per_token_diff)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let mut stream_forfeited = [0u64; MAX_REWARD_STREAMS];
+        for (index, amount) in stream_forfeited.iter_mut().enumerate() {
+            let rewards_per_token_diff = global_state.reward_streams[index].accumulated_rewards_per_token
+                .checked_sub(self.stream_rewards[index].rewards_per_token)
+                .ok_or(StakingError::MathOverflow)?;
+            *amount = math::reward_for_stake(expired_weight, rewards_per_token_diff)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+        
+        self.update_rewards(global_state)?;
+        self.pending_rewards = self.pending_rewards
+            .checked_sub(forfeited)
+            .ok_or(StakingError::MathOverflow)?;
+        for (index, checkpoint) in self.stream_rewards.iter_mut().enumerate() {
+            checkpoint.pending_rewards = checkpoint.pending_rewards
+                .checked_sub(stream_forfeited[index])
+                .ok_or(StakingError::MathOverflow)?;
+        }
+        
+        let old_weight = self.weighted_stake;
+        self.expire_boosts(current_time)?;
+        global_state.apply_weight_change(old_weight, self.weighted_stake)?;
+        
+        if forfeited > 0 {
+            global_state.distribute_rewards(forfeited)?;
+        }
+        let total_weighted_stake = global_state.total_weighted_stake;
+        for (index, amount) in stream_forfeited.iter().enumerate() {
+            if *amount > 0 {
+                global_state.reward_streams[index].distribute(*amount, total_weighted_stake)?;
+            }
+        }
+        
+        self.last_reward_update = current_time;
+        
+        Ok(())
+    }
+    
+    /// Mark `amount` of the reward stream at `index` as claimed
+    pub fn claim_stream_rewards(&mut self, index: usize, amount: u64) -> Result<()> {
+        let checkpoint = &mut self.stream_rewards[index];
+        checkpoint.pending_rewards = checkpoint.pending_rewards
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Portion of staked principal backing the given xLEASH amount
+    /// Releases the full principal when the position is fully redeemed
+    pub fn principal_for_xleash(&self, xleash_amount: u64) -> Result<u64> {
+        if xleash_amount >= self.xleash_amount {
+            return Ok(self.staked_amount);
+        }
+        
+        let principal = (self.staked_amount as u128)
+            .checked_mul(xleash_amount as u128)
+            .ok_or(StakingError::MathOverflow)?
+            / self.xleash_amount as u128;
+        
+        Ok(principal as u64)
+    }
+    
+    /// Stake tokens as a new deposit tranche unlocking after `lock_period`
+    /// When the tranche list is full, the deposit is merged into the newest tranche,
+    /// which takes the later unlock time and the combined weight
+    pub fn stake(
+        &mut self,
+        amount: u64,
+        xleash_amount: u64,
+        lock_period: i64,
+        multiplier_bps: u16,
+        current_time: i64,
+    ) -> Result<()> {
+        let staked_amount = self.staked_amount
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        let xleash_total = self.xleash_amount
+            .checked_add(xleash_amount)
+            .ok_or(StakingError::MathOverflow)?;
+        let unlock_time = current_time
+            .checked_add(lock_period)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let deposit = DepositTranche {
+            amount,
+            start_time: current_time,
+            unlock_time,
+            multiplier_bps,
+        };
+        
+        if self.deposits.len() < MAX_DEPOSIT_TRANCHES {
+            self.deposits.push(deposit);
+        } else {
+            // Unlocked deposits merge into an unlocked tranche when there is one, so
+            // restaked rewards stay withdrawable; otherwise merge into the newest tranche
+            let index = if unlock_time <= current_time {
+                self.deposits.iter().rposition(|tranche| tranche.unlock_time <= current_time)
+            } else {
+                None
+            };
+            let newest = match index {
+                Some(index) => &mut self.deposits[index],
+                None => self.deposits.last_mut().ok_or(StakingError::MathOverflow)?,
+            };
+            let merged_amount = newest.amount
+                .checked_add(amount)
+                .ok_or(StakingError::MathOverflow)?;
+            let merged_weight = newest.weight()?
+                .checked_add(deposit.weight()?)
+                .ok_or(StakingError::MathOverflow)?;
+            
+            newest.multiplier_bps = ((merged_weight as u128) * BPS_DENOMINATOR as u128
+                / merged_amount as u128) as u16;
+            newest.amount = merged_amount;
+            newest.unlock_time = newest.unlock_time.max(unlock_time);
+        }
+        
+        self.staked_amount = staked_amount;
+        self.xleash_amount = xleash_total;
+        self.refresh_lock()
+    }
+    
+    /// Drop the boost of tranches whose lock has expired
+    pub fn expire_boosts(&mut self, current_time: i64) -> Result<()> {
+        for tranche in self.deposits.iter_mut() {
+            if current_time >= tranche.unlock_time {
+                tranche.multiplier_bps = BPS_DENOMINATOR as u16;
+            }
+        }
+        
+        self.refresh_lock()
+    }
+    
+    /// Principal in tranches that have unlocked by the given time
+    pub fn unlocked_amount(&self, current_time: i64) -> Result<u64> {
+        self.deposits
+            .iter()
+            .filter(|tranche| current_time >= tranche.unlock_time)
+            .try_fold(0u64, |total, tranche| total.checked_add(tranche.amount))
+            .ok_or_else(|| error!(StakingError::MathOverflow))
+    }
+    
+    /// Whether the whole position can be unstaked at the given time
+    pub fn is_unlocked_at(&self, current_time: i64) -> bool {
+        !self.is_locked || current_time >= self.lock_end_time
+    }
+    
+    /// Seconds remaining until the whole position unlocks (zero once unlocked)
+    pub fn seconds_until_unlock(&self, current_time: i64) -> i64 {
+        if self.is_unlocked_at(current_time) {
+            0
+        } else {
+            self.lock_end_time - current_time
+        }
+    }
+    
+    /// Unstake tokens, consuming unlocked tranches oldest first
+    pub fn unstake(&mut self, amount: u64, xleash_amount: u64, current_time: i64) -> Result<()> {
+        require!(
+            self.unlocked_amount(current_time)? >= amount,
+            StakingError::LockPeriodNotMet
+        );
+        
+        let staked_amount = self.staked_amount
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        let xleash_total = self.xleash_amount
+            .checked_sub(xleash_amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let mut remaining = amount;
+        for tranche in self.deposits.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            if current_time >= tranche.unlock_time {
+                let taken = remaining.min(tranche.amount);
+                tranche.amount -= taken;
+                remaining -= taken;
+            }
+        }
+        self.deposits.retain(|tranche| tranche.amount > 0);
+        
+        self.staked_amount = staked_amount;
+        self.xleash_amount = xleash_total;
+        self.refresh_lock()
+    }
+    
+    /// Move unlocked principal into the unbonding queue
+    /// The queued `leash_amount` stops earning rewards and can be withdrawn after `unbonding_period`
+    pub fn request_unstake(
+        &mut self,
+        amount: u64,
+        xleash_amount: u64,
+        leash_amount: u64,
+        unbonding_period: i64,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(
+            self.unbonding.len() < MAX_UNBONDING_REQUESTS,
+            StakingError::TooManyUnbondingRequests
+        );
+        
+        let unlock_time = current_time
+            .checked_add(unbonding_period)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        self.unstake(amount, xleash_amount, current_time)?;
+        self.unbonding.push(UnbondingRequest {
+            amount: leash_amount,
+            request_time: current_time,
+            unlock_time,
+        });
+        
+        Ok(())
+    }
+    
+    /// Exit early, queueing `leash_amount` minus a penalty for unbonding
+    /// Unlocked tranches are consumed first, then locked tranches oldest first;
+    /// each locked tranche pays `max_penalty_bps` scaled by the fraction of its lock remaining
+    /// Returns the penalty in LEASH
+    pub fn early_unstake(
+        &mut self,
+        amount: u64,
+        xleash_amount: u64,
+        leash_amount: u64,
+        max_penalty_bps: u16,
+        unbonding_period: i64,
+        current_time: i64,
+    ) -> Result<u64> {
+        require!(
+            self.unbonding.len() < MAX_UNBONDING_REQUESTS,
+            StakingError::TooManyUnbondingRequests
+        );
+        require!(amount > 0, StakingError::InvalidStakeAmount);
+        
+        let staked_amount = self.staked_amount
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        let xleash_total = self.xleash_amount
+            .checked_sub(xleash_amount)
+            .ok_or(StakingError::MathOverflow)?;
+        let unlock_time = current_time
+            .checked_add(unbonding_period)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        let mut remaining = amount;
+        for tranche in self.deposits.iter_mut() {
+            if current_time >= tranche.unlock_time {
+                let taken = remaining.min(tranche.amount);
+                tranche.amount -= taken;
+                remaining -= taken;
+            }
+        }
+        
+        // Penalised principal, scaled by 1e4 * lock duration per tranche
+        let mut principal_penalty = 0u128;
+        for tranche in self.deposits.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            if current_time < tranche.unlock_time {
+                let taken = remaining.min(tranche.amount);
+                let time_left = (tranche.unlock_time - current_time) as u128;
+                let duration = (tranche.unlock_time - tranche.start_time).max(1) as u128;
+                let penalty = math::mul_div(
+                    taken as u128 * max_penalty_bps as u128,
+                    time_left.min(duration),
+                    duration * BPS_DENOMINATOR as u128,
+                )
+                .ok_or(StakingError::MathOverflow)?;
+                
+                principal_penalty += penalty;
+                tranche.amount -= taken;
+                remaining -= taken;
+            }
+        }
+        require!(remaining == 0, StakingError::InsufficientBalance);
+        self.deposits.retain(|tranche| tranche.amount > 0);
+        
+        // Charge the same fraction of the redeemed LEASH
+        let penalty = math::mul_div(leash_amount as u128, principal_penalty, amount as u128)
+            .ok_or(StakingError::MathOverflow)? as u64;
+        
+        self.unbonding.push(UnbondingRequest {
+            amount: leash_amount - penalty,
+            request_time: current_time,
+            unlock_time,
+        });
+        
+        self.staked_amount = staked_amount;
+        self.xleash_amount = xleash_total;
+        self.refresh_lock()?;
+        
+        Ok(penalty)
+    }
+    
+    /// Pending unbonding request at `index`
+    pub fn unbonding_request(&self, index: u8) -> Result<UnbondingRequest> {
+        self.unbonding
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| error!(StakingError::InvalidUnbondingRequest))
+    }
+    
+    /// Remove an unbonding request whose cooldown has elapsed, returning the LEASH to withdraw
+    pub fn complete_unstake(&mut self, index: u8, current_time: i64) -> Result<u64> {
+        let request = self.unbonding_request(index)?;
+        require!(
+            current_time >= request.unlock_time,
+            StakingError::UnbondingNotComplete
+        );
+        
+        self.unbonding.remove(index as usize);
+        
+        Ok(request.amount)
+    }
+    
+    /// Return an unbonding request to the position as an unlocked 1x deposit
+    pub fn cancel_unstake(&mut self, index: u8, xleash_amount: u64, current_time: i64) -> Result<u64> {
+        let request = self.unbonding_request(index)?;
+        
+        self.stake(request.amount, xleash_amount, 0, BPS_DENOMINATOR as u16, current_time)?;
+        self.unbonding.remove(index as usize);
+        
+        Ok(request.amount)
+    }
+    
+    /// Recompute the position-wide lock and reward weight from its tranches
+    fn refresh_lock(&mut self) -> Result<()> {
+        self.weighted_stake = self.deposits
+            .iter()
+            .try_fold(0u64, |total, tranche| {
+                total.checked_add(tranche.weight()?).ok_or_else(|| error!(StakingError::MathOverflow))
+            })?;
+        self.lock_end_time = self.deposits
+            .iter()
+            .map(|tranche| tranche.unlock_time)
+            .max()
+            .unwrap_or(0);
+        self.is_locked = !self.deposits.is_empty();
+        
+        Ok(())
+    }
+    
+    /// Restake `amount` of pending rewards as a new deposit tranche at 1x
+    pub fn compound_rewards(
+        &mut self,
+        amount: u64,
+        xleash_amount: u64,
+        lock_period: i64,
+        current_time: i64,
+    ) -> Result<()> {
+        let pending_rewards = self.pending_rewards
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        
+        self.stake(amount, xleash_amount, lock_period, BPS_DENOMINATOR as u16, current_time)?;
+        self.pending_rewards = pending_rewards;
+        
+        Ok(())
+    }
+    
+    /// Claim rewards
+    pub fn claim_rewards(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        self.pending_rewards = self.pending_rewards
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        self.last_claim_time = current_time;
+        
+        Ok(())
+    }
+}
+
+/// Lock status of a single position for queries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PositionLockStatus {
+    /// Owner of the position
+    pub owner: Pubkey,
+    
+    /// Amount of LEASH staked
+    pub staked_amount: u64,
+    
+    /// Whether any part of the position is still locked
+    pub is_locked: bool,
+    
+    /// Time at which the whole position unlocks
+    pub lock_end_time: i64,
+    
+    /// Seconds remaining until the whole position unlocks
+    pub seconds_until_unlock: i64,
+    
+    /// Principal that can be unstaked now
+    pub unlocked_amount: u64,
+    
+    /// Lock-boosted reward weight
+    pub weighted_stake: u64,
+    
+    /// Pending unbonding requests
+    pub unbonding: Vec<UnbondingRequest>,
+    
+    /// Individual deposits and their unlock times
+    pub deposits: Vec<DepositTranche>,
+    
+    /// Current timestamp
+    pub current_time: i64,
+}
+
+/// Staking statistics for queries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StakingStats {
+    /// Pool identifier
+    pub pool_id: u64,
+    
+    /// Mint staked in the pool
+    pub leash_mint: Pubkey,
+    
+    /// Key allowed to change parameters (default key once renounced)
+    pub authority: Pubkey,
+    
+    /// Proposed authority waiting to accept
+    pub pending_authority: Pubkey,
+    
+    /// Key allowed to toggle pause flags alongside the authority
+    pub guardian: Pubkey,
+    
+    /// Key allowed to distribute LP fees alongside the authority
+    pub fee_distributor: Pubkey,
+    
+    /// Key allowed to set the reward rate within the managed bounds
+    pub rate_manager: Pubkey,
+    
+    /// Total amount staked
+    pub total_staked: u64,
+    
+    /// Total xLEASH minted
+    pub total_xleash_minted: u64,
+    
+    /// Total lock-boosted reward weight
+    pub total_weighted_stake: u64,
+    
+    /// End of the current reward period
+    pub period_finish: i64,
+    
+    /// Total LEASH waiting out the unbonding cooldown
+    pub total_unbonding: u64,
+    
+    /// Current reward rate
+    pub reward_rate: u64,
+    
+    /// Accumulated rewards per token
+    pub accumulated_rewards_per_token: u128,
+    
+    /// LEASH redeemable per xLEASH, scaled by `EXCHANGE_RATE_PRECISION`
+    pub exchange_rate: u64,
+    
+    /// Total rewards distributed
+    pub total_rewards_distributed: u64,
+    
+    /// LP fees collected
+    pub lp_fees_collected: u64,
+    
+    /// Number of active stakers
+    pub active_stakers: u32,
+    
+    /// Whether any operation is paused
+    pub is_paused: bool,
+    
+    /// Per-operation pause flags
+    pub pause_flags: u8,
+    
+    /// Current timestamp
+    pub current_time: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn global_state() -> GlobalStakingState {
+        GlobalStakingState {
+            authority: Pubkey::new_unique(),
+            leash_mint: Pubkey::new_unique(),
+            xleash_mint: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            stake_vault: Pubkey::new_unique(),
+            reward_vault: Pubkey::new_unique(),
+            reward_rate: MAX_REWARD_RATE,
+            min_stake_amount: 1,
+            max_stake_amount: u64::MAX,
+            lock_period: MIN_LOCK_PERIOD,
+            lock_tiers: DEFAULT_LOCK_TIERS,
+            total_staked: 1_000,
+            total_weighted_stake: 1_000,
+            total_xleash_minted: 1_000,
+            active_stakers: 1,
+            accumulated_rewards_per_token: 0,
+            last_update_time: 0,
+            total_rewards_distributed: 0,
+            total_rewards_funded: 0,
+            lp_fees_collected: 0,
+            undistributed_lp_fees: 0,
+            is_paused: false,
+            bump: 255,
+            vault_authority_bump: 255,
+            pause_flags: 0,
+            guardian: Pubkey::default(),
+            unbonding_period: DEFAULT_UNBONDING_PERIOD,
+            total_unbonding: 0,
+            early_exit_penalty_bps: DEFAULT_EARLY_EXIT_PENALTY_BPS,
+            penalty_staker_share_bps: DEFAULT_PENALTY_STAKER_SHARE_BPS,
+            keeper_fee_bps: DEFAULT_KEEPER_FEE_BPS,
+            period_finish: i64::MAX,
+            reward_streams: [RewardStream::default(); MAX_REWARD_STREAMS],
+            pool_id: 0,
+            pending_authority: Pubkey::default(),
+            fee_distributor: Pubkey::default(),
+            rate_manager: Pubkey::default(),
+            min_managed_reward_rate: 0,
+            max_managed_reward_rate: 0,
+            param_timelock: 0,
+            param_change_nonce: 0,
+            rewards_owed: 0,
+            reserved: [0; 56],
+        }
+    }
+    
+    fn position() -> UserStakingPosition {
+        UserStakingPosition {
+            owner: Pubkey::new_unique(),
+            global_staking_state: Pubkey::new_unique(),
+            staked_amount: 1_000,
+            xleash_amount: 1_000,
+            weighted_stake: 1_000,
+            user_rewards_per_token: 0,
+            pending_rewards: 0,
+            staking_start_time: 0,
+            last_claim_time: 0,
+            is_locked: true,
+            lock_end_time: 0,
+            bump: 255,
+            auto_compound: false,
+            last_reward_update: 0,
+            reserved: [0; 23],
+            stream_rewards: [StreamReward::default(); MAX_REWARD_STREAMS],
+            deposits: vec![DepositTranche {
+                amount: 1_000,
+                start_time: 0,
+                unlock_time: 0,
+                multiplier_bps: BPS_DENOMINATOR as u16,
+            }],
+            unbonding: Vec::new(),
+        }
+    }
+    
+    fn no_schedule() -> EmissionSchedule {
+        EmissionSchedule {
+            global_staking_state: Pubkey::new_unique(),
+            bump: 255,
+            breakpoints: Vec::new(),
+        }
+    }
+    
+    fn assert_overflow<T: std::fmt::Debug>(result: Result<T>) {
+        assert_eq!(result.unwrap_err(), error!(StakingError::MathOverflow));
+    }
+    
+    #[test]
+    fn update_rewards_reverts_when_accumulator_overflows() {
+        let mut state = global_state();
+        state.accumulated_rewards_per_token = u128::MAX - 1;
+        
+        assert_overflow(state.update_rewards(&no_schedule(), 10));
+        assert_eq!(state.accumulated_rewards_per_token, u128::MAX - 1);
+        assert_eq!(state.last_update_time, 0);
+    }
+    
+    #[test]
+    fn update_rewards_reverts_when_emission_overflows() {
+        let mut state = global_state();
+        state.total_weighted_stake = 1;
+        
+        // rate * dt * 1e18 does not fit in u128 over a large enough interval
+        assert_overflow(state.rewards_per_token_at(&no_schedule(), i64::MAX));
+        assert_overflow(state.update_rewards(&no_schedule(), i64::MAX));
+        assert_eq!(state.accumulated_rewards_per_token, 0);
+    }
+    
+    #[test]
+    fn update_rewards_accrues_within_bounds() {
+        let mut state = global_state();
+        
+        state.update_rewards(&no_schedule(), 10).unwrap();
+        assert_eq!(
+            state.accumulated_rewards_per_token,
+            math::emission_per_token(MAX_REWARD_RATE, 10, 1_000).unwrap()
+        );
+        assert_eq!(state.last_update_time, 10);
+    }
+    
+    #[test]
+    fn add_lp_fees_reverts_when_counters_overflow() {
+        let mut state = global_state();
+        state.lp_fees_collected = u64::MAX;
+        
+        assert_overflow(state.add_lp_fees(1));
+        assert_eq!(state.lp_fees_collected, u64::MAX);
+        assert_eq!(state.undistributed_lp_fees, 0);
+        assert_eq!(state.accumulated_rewards_per_token, 0);
+        
+        let mut state = global_state();
+        state.undistributed_lp_fees = u64::MAX;
+        
+        assert_overflow(state.add_lp_fees(1));
+        assert_eq!(state.lp_fees_collected, 0);
+        assert_eq!(state.undistributed_lp_fees, u64::MAX);
+    }
+    
+    #[test]
+    fn add_lp_fees_reverts_when_accumulator_overflows() {
+        let mut state = global_state();
+        state.accumulated_rewards_per_token = u128::MAX;
+        
+        assert_overflow(state.add_lp_fees(1_000));
+        assert_eq!(state.accumulated_rewards_per_token, u128::MAX);
+        assert_eq!(state.lp_fees_collected, 0);
+    }
+    
+    #[test]
+    fn add_lp_fees_carries_forward_when_nothing_staked() {
+        let mut state = global_state();
+        state.total_weighted_stake = 0;
+        
+        state.add_lp_fees(500).unwrap();
+        assert_eq!(state.undistributed_lp_fees, 500);
+        assert_eq!(state.accumulated_rewards_per_token, 0);
+        
+        state.total_weighted_stake = 1_000;
+        state.add_lp_fees(500).unwrap();
+        assert_eq!(state.undistributed_lp_fees, 0);
+        assert_eq!(state.accumulated_rewards_per_token, math::REWARD_PRECISION);
+    }
+    
+    #[test]
+    fn compound_lp_fees_reverts_when_total_staked_overflows() {
+        let mut state = global_state();
+        state.total_staked = u64::MAX;
+        
+        assert_overflow(state.compound_lp_fees(1));
+        assert_eq!(state.total_staked, u64::MAX);
+        assert_eq!(state.lp_fees_collected, 0);
+    }
+    
+    #[test]
+    fn share_conversions_revert_instead_of_truncating() {
+        let mut state = global_state();
+        state.total_staked = 1;
+        state.total_xleash_minted = u64::MAX;
+        
+        assert_overflow(state.xleash_for_deposit(2));
+        
+        state.total_staked = u64::MAX;
+        state.total_xleash_minted = 1;
+        
+        assert_overflow(state.exchange_rate());
+    }
+    
+    #[test]
+    fn position_stake_reverts_at_boundary() {
+        let mut user = position();
+        user.staked_amount = u64::MAX;
+        
+        assert_overflow(user.stake(1, 1, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, 10));
+        assert_eq!(user.staked_amount, u64::MAX);
+        assert_eq!(user.xleash_amount, 1_000);
+        assert_eq!(user.staking_start_time, 0);
+        
+        let mut user = position();
+        user.xleash_amount = u64::MAX;
+        
+        assert_overflow(user.stake(1, 1, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, 10));
+        assert_eq!(user.staked_amount, 1_000);
+        assert_eq!(user.xleash_amount, u64::MAX);
+    }
+    
+    #[test]
+    fn position_unstake_reverts_instead_of_zeroing() {
+        let mut user = position();
+        
+        assert_eq!(
+            user.unstake(1_001, 1, 10).unwrap_err(),
+            error!(StakingError::LockPeriodNotMet)
+        );
+        assert_overflow(user.unstake(1, 1_001, 10));
+        assert_eq!(user.staked_amount, 1_000);
+        assert_eq!(user.xleash_amount, 1_000);
+        assert_eq!(user.deposits[0].amount, 1_000);
+        
+        user.unstake(1_000, 1_000, 10).unwrap();
+        assert_eq!(user.staked_amount, 0);
+        assert_eq!(user.xleash_amount, 0);
+        assert!(user.deposits.is_empty());
+        assert!(!user.is_locked);
+    }
+    
+    #[test]
+    fn top_ups_do_not_reset_earlier_tranches() {
+        let mut user = position();
+        user.stake(500, 500, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, 100).unwrap();
+        
+        assert_eq!(user.deposits.len(), 2);
+        assert_eq!(user.deposits[0].unlock_time, 0);
+        assert_eq!(user.deposits[1].start_time, 100);
+        assert_eq!(user.deposits[1].unlock_time, 100 + MIN_LOCK_PERIOD);
+        assert_eq!(user.staking_start_time, 0);
+        assert_eq!(user.lock_end_time, 100 + MIN_LOCK_PERIOD);
+        
+        // Only the first deposit is unlocked
+        assert_eq!(user.unlocked_amount(200).unwrap(), 1_000);
+        assert!(!user.is_unlocked_at(200));
+        assert_eq!(user.seconds_until_unlock(200), MIN_LOCK_PERIOD - 100);
+    }
+    
+    #[test]
+    fn unstake_consumes_unlocked_tranches_fifo() {
+        let mut user = position();
+        user.stake(500, 500, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, 100).unwrap();
+        user.stake(250, 250, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, 200).unwrap();
+        
+        // Locked tranches cannot be touched
+        assert_eq!(
+            user.unstake(1_001, 1_001, 200).unwrap_err(),
+            error!(StakingError::LockPeriodNotMet)
+        );
+        assert_eq!(user.staked_amount, 1_750);
+        
+        // Once the first two unlock, the oldest is drained before the second
+        let current_time = 150 + MIN_LOCK_PERIOD;
+        user.unstake(1_200, 1_200, current_time).unwrap();
+        assert_eq!(user.deposits.len(), 2);
+        assert_eq!(user.deposits[0].amount, 300);
+        assert_eq!(user.deposits[0].start_time, 100);
+        assert_eq!(user.deposits[1].amount, 250);
+        assert_eq!(user.staked_amount, 550);
+        assert_eq!(user.unlocked_amount(current_time).unwrap(), 300);
+    }
+    
+    #[test]
+    fn full_tranche_list_merges_into_newest() {
+        let mut user = position();
+        for i in 1..MAX_DEPOSIT_TRANCHES as i64 {
+            user.stake(10, 10, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, i).unwrap();
+        }
+        assert_eq!(user.deposits.len(), MAX_DEPOSIT_TRANCHES);
+        
+        user.stake(10, 10, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, 1_000).unwrap();
+        assert_eq!(user.deposits.len(), MAX_DEPOSIT_TRANCHES);
+        
+        let newest = user.deposits.last().unwrap();
+        assert_eq!(newest.amount, 20);
+        assert_eq!(newest.unlock_time, 1_000 + MIN_LOCK_PERIOD);
+        
+        let total: u64 = user.deposits.iter().map(|tranche| tranche.amount).sum();
+        assert_eq!(total, user.staked_amount);
+    }
+    
+    #[test]
+    fn stake_reverts_when_unlock_time_overflows() {
+        let mut user = position();
+        
+        assert_overflow(user.stake(1, 1, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, i64::MAX));
+        assert_eq!(user.staked_amount, 1_000);
+        assert_eq!(user.deposits.len(), 1);
+    }
+    
+    #[test]
+    fn position_claim_reverts_when_exceeding_pending() {
+        let mut user = position();
+        user.pending_rewards = 100;
+        
+        assert_overflow(user.claim_rewards(101, 10));
+        assert_eq!(user.pending_rewards, 100);
+        assert_eq!(user.last_claim_time, 0);
+    }
+    
+    #[test]
+    fn pending_rewards_revert_when_checkpoint_ahead_of_global() {
+        let state = global_state();
+        let mut user = position();
+        user.user_rewards_per_token = 1;
+        
+        assert_overflow(user.calculate_pending_rewards(&state));
+        assert_overflow(user.update_rewards(&state));
+        assert_eq!(user.user_rewards_per_token, 1);
+    }
+    
+    #[test]
+    fn pending_rewards_revert_when_exceeding_u64() {
+        let mut state = global_state();
+        let mut user = position();
+        state.accumulated_rewards_per_token = math::REWARD_PRECISION;
+        user.pending_rewards = u64::MAX;
+        
+        assert_overflow(user.calculate_pending_rewards(&state));
+        assert_overflow(user.update_rewards(&state));
+        assert_eq!(user.pending_rewards, u64::MAX);
+        assert_eq!(user.user_rewards_per_token, 0);
+        
+        user.pending_rewards = 0;
+        user.weighted_stake = u64::MAX;
+        state.accumulated_rewards_per_token = math::REWARD_PRECISION * 2;
+        
+        assert_overflow(user.calculate_pending_rewards(&state));
+    }
+    
+    #[test]
+    fn lock_tiers_boost_weight_until_unlock() {
+        let mut state = global_state();
+        let (duration, multiplier_bps) = state.lock_terms(Some(2)).unwrap();
+        assert_eq!((duration, multiplier_bps), (31536000, 20_000));
+        assert_eq!(state.lock_terms(None).unwrap(), (MIN_LOCK_PERIOD, 10_000));
+        assert_eq!(
+            state.lock_terms(Some(MAX_LOCK_TIERS as u8)).unwrap_err(),
+            error!(StakingError::InvalidLockTier)
+        );
+        
+        let mut user = position();
+        let old_weight = user.weighted_stake;
+        user.stake(500, 500, duration, multiplier_bps, 100).unwrap();
+        assert_eq!(user.weighted_stake, 2_000);
+        state.apply_weight_change(old_weight, user.weighted_stake).unwrap();
+        assert_eq!(state.total_weighted_stake, 2_000);
+        
+        // Boost stays until the tranche unlocks, then falls back to 1x
+        user.expire_boosts(100 + duration - 1).unwrap();
+        assert_eq!(user.weighted_stake, 2_000);
+        user.expire_boosts(100 + duration).unwrap();
+        assert_eq!(user.weighted_stake, 1_500);
+    }
+    
+    #[test]
+    fn boosted_rewards_stop_at_unlock() {
+        let mut state = global_state();
+        let mut user = position();
+        user.last_reward_update = 100;
+        user.stake(500, 500, 1_000, 20_000, 100).unwrap();
+        assert_eq!(user.weighted_stake, 2_000);
+        
+        // Another staker holds 2_000 weight; 8_000 accrues while the user is still boosted
+        state.total_weighted_stake = 4_000;
+        state.distribute_rewards(8_000).unwrap();
+        
+        // Settled halfway between unlock and now: the boost earned only for the first half
+        user.settle_rewards(&mut state, 2_100).unwrap();
+        assert_eq!(user.pending_rewards, 3_500);
+        assert_eq!(user.weighted_stake, 1_500);
+        assert_eq!(state.total_weighted_stake, 3_500);
+        assert_eq!(user.last_reward_update, 2_100);
+        
+        // The forfeited 500 is shared by everyone at their unboosted weight
+        assert_eq!(user.calculate_pending_rewards(&state).unwrap(), 3_500 + 214);
+        
+        // Settling again forfeits nothing more
+        user.settle_rewards(&mut state, 3_000).unwrap();
+        assert_eq!(user.pending_rewards, 3_714);
+    }
+    
+    #[test]
+    fn settling_twice_across_a_skip_moves_global_state_once() {
+        let mut state = global_state();
+        let mut user = position();
+        user.last_reward_update = 100;
+        user.stake(500, 500, 1_000, 20_000, 100).unwrap();
+        state.total_weighted_stake = 4_000;
+        state.distribute_rewards(8_000).unwrap();
+        let stale = user.clone();
+        
+        // First crank pass settles the position, then skips it
+        user.settle_rewards(&mut state, 2_100).unwrap();
+        let settled_weight = state.total_weighted_stake;
+        let settled_accumulator = state.accumulated_rewards_per_token;
+        
+        // Re-settling the persisted checkpoint changes nothing
+        user.settle_rewards(&mut state, 2_100).unwrap();
+        assert_eq!(state.total_weighted_stake, settled_weight);
+        assert_eq!(state.accumulated_rewards_per_token, settled_accumulator);
+        
+        // Had the skip dropped the write, the stale copy would forfeit the boost again
+        let mut replayed = state.clone();
+        let mut stale = stale;
+        stale.settle_rewards(&mut replayed, 2_100).unwrap();
+        assert_eq!(replayed.total_weighted_stake, settled_weight - 500);
+    }
+    
+    #[test]
+    fn merged_tranche_keeps_combined_weight() {
+        let mut user = position();
+        for i in 1..MAX_DEPOSIT_TRANCHES as i64 {
+            user.stake(10, 10, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, i).unwrap();
+        }
+        
+        user.stake(10, 10, MIN_LOCK_PERIOD, 30_000, 1_000).unwrap();
+        let newest = user.deposits.last().unwrap();
+        assert_eq!(newest.amount, 20);
+        assert_eq!(newest.multiplier_bps, 20_000);
+        assert_eq!(user.weighted_stake, 1_000 + 140 + 40);
+    }
+    
+    #[test]
+    fn lock_tier_validation() {
+        assert!(GlobalStakingState::validate_lock_tiers(&DEFAULT_LOCK_TIERS).is_ok());
+        
+        let mut tiers = DEFAULT_LOCK_TIERS;
+        tiers[1].duration = tiers[0].duration;
+        assert!(GlobalStakingState::validate_lock_tiers(&tiers).is_err());
+        
+        let mut tiers = DEFAULT_LOCK_TIERS;
+        tiers[0].multiplier_bps = 9_999;
+        assert!(GlobalStakingState::validate_lock_tiers(&tiers).is_err());
+        
+        let mut tiers = DEFAULT_LOCK_TIERS;
+        tiers[3].multiplier_bps = MAX_MULTIPLIER_BPS + 1;
+        assert!(GlobalStakingState::validate_lock_tiers(&tiers).is_err());
+    }
+    
+    #[test]
+    fn unbonding_requests_wait_out_cooldown() {
+        let mut user = position();
+        
+        user.request_unstake(400, 400, 440, DEFAULT_UNBONDING_PERIOD, 10).unwrap();
+        assert_eq!(user.staked_amount, 600);
+        assert_eq!(user.weighted_stake, 600);
+        assert_eq!(user.unbonding[0].amount, 440);
+        
+        assert_eq!(
+            user.complete_unstake(0, 10 + DEFAULT_UNBONDING_PERIOD - 1).unwrap_err(),
+            error!(StakingError::UnbondingNotComplete)
+        );
+        assert_eq!(
+            user.complete_unstake(1, 10 + DEFAULT_UNBONDING_PERIOD).unwrap_err(),
+            error!(StakingError::InvalidUnbondingRequest)
+        );
+        assert_eq!(user.complete_unstake(0, 10 + DEFAULT_UNBONDING_PERIOD).unwrap(), 440);
+        assert!(user.unbonding.is_empty());
+    }
+    
+    #[test]
+    fn cancelled_unbonding_is_restaked_unlocked() {
+        let mut user = position();
+        user.request_unstake(400, 400, 400, DEFAULT_UNBONDING_PERIOD, 10).unwrap();
+        user.request_unstake(100, 100, 100, DEFAULT_UNBONDING_PERIOD, 20).unwrap();
+        
+        assert_eq!(user.cancel_unstake(0, 390, 30).unwrap(), 400);
+        assert_eq!(user.unbonding.len(), 1);
+        assert_eq!(user.unbonding[0].amount, 100);
+        assert_eq!(user.staked_amount, 900);
+        assert_eq!(user.xleash_amount, 890);
+        assert_eq!(user.unlocked_amount(30).unwrap(), 900);
+    }
+    
+    #[test]
+    fn unbonding_queue_is_bounded() {
+        let mut user = position();
+        for i in 0..MAX_UNBONDING_REQUESTS as i64 {
+            user.request_unstake(1, 1, 1, DEFAULT_UNBONDING_PERIOD, i).unwrap();
+        }
+        
+        assert_eq!(
+            user.request_unstake(1, 1, 1, DEFAULT_UNBONDING_PERIOD, 100).unwrap_err(),
+            error!(StakingError::TooManyUnbondingRequests)
+        );
+        assert_eq!(user.staked_amount, 1_000 - MAX_UNBONDING_REQUESTS as u64);
+    }
+    
+    #[test]
+    fn early_unstake_penalty_decays_linearly() {
+        let mut user = position();
+        user.stake(1_000, 1_000, 1_000, BPS_DENOMINATOR as u16, 0).unwrap();
+        
+        // Unlocked principal is taken first and carries no penalty
+        let penalty = user.early_unstake(1_000, 1_000, 1_000, 5_000, 0, 250).unwrap();
+        assert_eq!(penalty, 0);
+        
+        // A quarter of the way into the lock, three quarters of the 50% max penalty applies
+        let penalty = user.early_unstake(400, 400, 800, 5_000, 0, 250).unwrap();
+        assert_eq!(penalty, 300);
+        assert_eq!(user.unbonding[1].amount, 500);
+        assert_eq!(user.staked_amount, 600);
+        assert_eq!(user.deposits[0].amount, 600);
+        
+        // Nothing is owed once the lock has elapsed
+        let penalty = user.early_unstake(600, 600, 600, 5_000, 0, 1_000).unwrap();
+        assert_eq!(penalty, 0);
+        assert!(user.deposits.is_empty());
+    }
+    
+    #[test]
+    fn penalty_split_follows_staker_share() {
+        let mut state = global_state();
+        assert_eq!(state.split_penalty(1_001).unwrap(), (500, 501));
+        
+        state.penalty_staker_share_bps = 10_000;
+        assert_eq!(state.split_penalty(1_001).unwrap(), (1_001, 0));
+        
+        assert!(GlobalStakingState::validate_penalty_params(10_000, 0).is_ok());
+        assert!(GlobalStakingState::validate_penalty_params(10_001, 0).is_err());
+    }
+    
+    #[test]
+    fn compound_moves_pending_rewards_into_stake() {
+        let mut user = position();
+        user.pending_rewards = 300;
+        
+        assert_overflow(user.compound_rewards(301, 301, MIN_LOCK_PERIOD, 10));
+        assert_eq!(user.staked_amount, 1_000);
+        
+        user.compound_rewards(300, 250, MIN_LOCK_PERIOD, 10).unwrap();
+        assert_eq!(user.pending_rewards, 0);
+        assert_eq!(user.staked_amount, 1_300);
+        assert_eq!(user.xleash_amount, 1_250);
+        assert_eq!(user.weighted_stake, 1_300);
+        assert_eq!(user.deposits[1].unlock_time, 10 + MIN_LOCK_PERIOD);
+    }
+    
+    #[test]
+    fn unlocked_compound_never_extends_locks() {
+        let mut user = position();
+        for i in 1..MAX_DEPOSIT_TRANCHES as i64 {
+            user.stake(10, 10, MIN_LOCK_PERIOD, BPS_DENOMINATOR as u16, i).unwrap();
+        }
+        user.pending_rewards = 200;
+        
+        // Merges into the already unlocked first tranche
+        user.compound_rewards(100, 100, 0, 50).unwrap();
+        assert_eq!(user.deposits[0].amount, 1_100);
+        assert_eq!(user.deposits[0].unlock_time, 50);
+        assert_eq!(user.deposits.last().unwrap().unlock_time, 15 + MIN_LOCK_PERIOD);
+        
+        // With every tranche locked it joins the newest without moving its unlock
+        user.deposits[0].unlock_time = 100;
+        user.compound_rewards(100, 100, 0, 50).unwrap();
+        assert_eq!(user.deposits.last().unwrap().amount, 110);
+        assert_eq!(user.deposits.last().unwrap().unlock_time, 15 + MIN_LOCK_PERIOD);
+    }
+    
+    #[test]
+    fn stake_capacity_is_bounded_by_max_stake() {
+        let mut state = global_state();
+        state.max_stake_amount = 1_500;
+        
+        assert!(state.check_stake_capacity(500).is_ok());
+        assert_eq!(
+            state.check_stake_capacity(501).unwrap_err(),
+            error!(StakingError::StakingPoolFull)
+        );
+        assert_eq!(
+            state.check_stake_capacity(1_501).unwrap_err(),
+            error!(StakingError::InvalidStakeAmount)
+        );
+    }
+    
+    #[test]
+    fn keeper_fee_rounds_down() {
+        let mut state = global_state();
+        assert_eq!(state.keeper_fee(10_000).unwrap(), 10);
+        assert_eq!(state.keeper_fee(999).unwrap(), 0);
+        
+        state.keeper_fee_bps = MAX_KEEPER_FEE_BPS;
+        assert_eq!(state.keeper_fee(u64::MAX).unwrap(), u64::MAX / 100);
+    }
+    
+    #[test]
+    fn reward_streams_accrue_alongside_leash() {
+        let mut state = global_state();
+        state.reward_rate = 100;
+        let index = state.add_reward_stream(Pubkey::new_unique(), Pubkey::new_unique(), 50).unwrap() as usize;
+        
+        let mut user = position();
+        user.update_rewards(&state).unwrap();
+        
+        state.update_rewards(&no_schedule(), 10).unwrap();
+        user.update_rewards(&state).unwrap();
+        assert_eq!(user.pending_rewards, 1_000);
+        assert_eq!(user.stream_rewards[index].pending_rewards, 500);
+        
+        // Fees distributed in the stream's own token
+        state.reward_streams[index].distribute(1_000, state.total_weighted_stake).unwrap();
+        user.update_rewards(&state).unwrap();
+        assert_eq!(user.stream_rewards[index].pending_rewards, 1_500);
+        
+        user.claim_stream_rewards(index, 1_500).unwrap();
+        assert_overflow(user.claim_stream_rewards(index, 1));
+    }
+    
+    #[test]
+    fn reward_stream_slots_are_bounded_and_unique() {
+        let mut state = global_state();
+        let mint = Pubkey::new_unique();
+        state.add_reward_stream(mint, Pubkey::new_unique(), 0).unwrap();
+        
+        assert_eq!(
+            state.add_reward_stream(mint, Pubkey::new_unique(), 0).unwrap_err(),
+            error!(StakingError::InvalidRewardStream)
+        );
+        assert_eq!(
+            state.add_reward_stream(state.leash_mint, Pubkey::new_unique(), 0).unwrap_err(),
+            error!(StakingError::InvalidRewardStream)
+        );
+        
+        for _ in 1..MAX_REWARD_STREAMS {
+            state.add_reward_stream(Pubkey::new_unique(), Pubkey::new_unique(), 0).unwrap();
+        }
+        assert_eq!(
+            state.add_reward_stream(Pubkey::new_unique(), Pubkey::new_unique(), 0).unwrap_err(),
+            error!(StakingError::RewardStreamLimitReached)
+        );
+        assert!(state.reward_stream_mut(MAX_REWARD_STREAMS as u8).is_err());
+    }
+    
+    #[test]
+    fn emissions_stop_at_period_finish() {
+        let mut state = global_state();
+        state.period_finish = 0;
+        state.notify_reward_amount(1_000, 100, 0).unwrap();
+        assert_eq!(state.reward_rate, 10);
+        assert_eq!(state.period_finish, 100);
+        
+        state.update_rewards(&no_schedule(), 50).unwrap();
+        state.update_rewards(&no_schedule(), 500).unwrap();
+        assert_eq!(state.accumulated_rewards_per_token, math::emission_per_token(10, 100, 1_000).unwrap());
+    }
+    
+    #[test]
+    fn funding_excludes_rewards_already_owed() {
+        let mut state = global_state();
+        state.period_finish = 0;
+        state.notify_reward_amount(1_000, 100, 0).unwrap();
+        assert!(state.is_reward_period_active(99));
+        assert!(!state.is_reward_period_active(100));
+        
+        // Emissions and received fees become owed; payouts release them
+        state.update_rewards(&no_schedule(), 40).unwrap();
+        state.receive_rewards(60).unwrap();
+        assert_eq!(state.rewards_owed, 460);
+        assert_eq!(state.unowed_rewards(1_060), 600);
+        
+        state.record_rewards_paid(100).unwrap();
+        assert_eq!(state.rewards_owed, 360);
+        assert_eq!(state.total_rewards_distributed, 100);
+        assert_eq!(state.unowed_rewards(300), 0);
+        
+        // Paying out more than is owed is an accounting error
+        assert_overflow(state.record_rewards_paid(361));
+        
+        // Nothing is owed for time with no stake
+        state.total_weighted_stake = 0;
+        state.update_rewards(&no_schedule(), 100).unwrap();
+        assert_eq!(state.rewards_owed, 360);
+    }
+    
+    #[test]
+    fn notify_rolls_leftover_into_next_period() {
+        let mut state = global_state();
+        state.period_finish = 0;
+        state.notify_reward_amount(1_000, 100, 0).unwrap();
+        
+        // Halfway through, 500 is still unemitted and joins the new amount
+        state.update_rewards(&no_schedule(), 50).unwrap();
+        state.notify_reward_amount(1_500, 200, 50).unwrap();
+        assert_eq!(state.reward_rate, 10);
+        assert_eq!(state.period_finish, 250);
+        
+        // After the period ends nothing is left over
+        state.update_rewards(&no_schedule(), 300).unwrap();
+        state.notify_reward_amount(100, 10, 300).unwrap();
+        assert_eq!(state.reward_rate, 10);
+        
+        assert_eq!(
+            state.notify_reward_amount(1, 10, 400).unwrap_err(),
+            error!(StakingError::InvalidRewardRate)
+        );
+        assert_eq!(
+            state.notify_reward_amount(1_000, 0, 400).unwrap_err(),
+            error!(StakingError::InvalidRewardDuration)
+        );
+    }
+    
+    #[test]
+    fn schedule_integrates_across_breakpoints() {
+        let schedule = EmissionSchedule {
+            global_staking_state: Pubkey::new_unique(),
+            bump: 255,
+            breakpoints: vec![
+                EmissionBreakpoint { start_time: 100, reward_rate: 40 },
+                EmissionBreakpoint { start_time: 200, reward_rate: 20 },
+                EmissionBreakpoint { start_time: 300, reward_rate: 10 },
+            ],
+        };
+        
+        assert_eq!(schedule.rate_at(99), 0);
+        assert_eq!(schedule.rate_at(250), 20);
+        assert_eq!(schedule.emitted_between(0, 100), Some(0));
+        assert_eq!(schedule.emitted_between(150, 350), Some(50 * 40 + 100 * 20 + 50 * 10));
+        
+        // A single update spanning every halving matches stepping through them
+        let mut state = global_state();
+        state.update_rewards(&schedule, 350).unwrap();
+        let mut stepped = global_state();
+        for time in [100, 200, 300, 350] {
+            stepped.update_rewards(&schedule, time).unwrap();
+        }
+        assert_eq!(state.accumulated_rewards_per_token, stepped.accumulated_rewards_per_token);
+        assert_eq!(state.accumulated_rewards_per_token, math::amount_per_token(6_500, 1_000).unwrap());
+        assert_eq!(state.reward_rate, 10);
+    }
+    
+    #[test]
+    fn authority_transfer_requires_acceptance() {
+        let mut state = global_state();
+        let old_authority = state.authority;
+        let new_authority = Pubkey::new_unique();
+        
+        // Nothing pending yet
+        assert!(state.accept_authority(&new_authority).is_err());
+        
+        state.propose_authority(new_authority);
+        assert_eq!(state.authority, old_authority);
+        assert!(state.accept_authority(&Pubkey::new_unique()).is_err());
+        
+        state.accept_authority(&new_authority).unwrap();
+        assert_eq!(state.authority, new_authority);
+        assert_eq!(state.pending_authority, Pubkey::default());
+        
+        // Proposing the default key cancels
+        state.propose_authority(old_authority);
+        state.propose_authority(Pubkey::default());
+        assert!(state.accept_authority(&old_authority).is_err());
+    }
+    
+    #[test]
+    fn renounce_freezes_authority() {
+        let mut state = global_state();
+        let authority = state.authority;
+        state.propose_authority(Pubkey::new_unique());
+        
+        state.renounce_authority();
+        assert!(state.is_renounced());
+        assert_eq!(state.pending_authority, Pubkey::default());
+        assert!(!state.can_pause(&authority));
+        assert!(!state.can_pause(&Pubkey::default()));
+    }
+    
+    #[test]
+    fn roles_are_scoped() {
+        let mut state = global_state();
+        let distributor = Pubkey::new_unique();
+        let rate_manager = Pubkey::new_unique();
+        
+        // Unassigned roles never match, even the default key
+        assert!(!state.can_distribute_fees(&Pubkey::default()));
+        assert!(!state.is_rate_manager(&Pubkey::default()));
+        assert!(state.can_distribute_fees(&state.authority));
+        
+        state.fee_distributor = distributor;
+        state.rate_manager = rate_manager;
+        state.min_managed_reward_rate = 10;
+        state.max_managed_reward_rate = 20;
+        assert!(state.can_distribute_fees(&distributor));
+        assert!(!state.can_distribute_fees(&rate_manager));
+        assert!(!state.can_pause(&distributor));
+        assert!(state.is_rate_manager(&rate_manager));
+        assert!(!state.is_rate_manager(&state.authority));
+        
+        assert!(state.check_managed_reward_rate(10).is_ok());
+        assert!(state.check_managed_reward_rate(20).is_ok());
+        assert!(state.check_managed_reward_rate(9).is_err());
+        assert!(state.check_managed_reward_rate(21).is_err());
+        
+        assert!(GlobalStakingState::validate_managed_rate_bounds(20, 10).is_err());
+        assert!(GlobalStakingState::validate_managed_rate_bounds(0, MAX_REWARD_RATE + 1).is_err());
+        
+        // Renouncing freezes the rate but leaves fee distribution running
+        state.renounce_authority();
+        assert!(!state.is_rate_manager(&rate_manager));
+        assert!(state.can_distribute_fees(&distributor));
+    }
+    
+    #[test]
+    fn param_change_applies_only_given_fields() {
+        let mut state = global_state();
+        let change = ParamChange {
+            lock_period: Some(MIN_LOCK_PERIOD * 2),
+            param_timelock: Some(86400),
+            ..ParamChange::default()
+        };
+        
+        state.apply_param_change(&change).unwrap();
+        assert_eq!(state.lock_period, MIN_LOCK_PERIOD * 2);
+        assert_eq!(state.param_timelock, 86400);
+        assert_eq!(state.reward_rate, MAX_REWARD_RATE);
+        assert_eq!(state.unbonding_period, DEFAULT_UNBONDING_PERIOD);
+        assert_eq!(state.lock_tiers, DEFAULT_LOCK_TIERS);
+        
+        // Lock tiers, penalties and the keeper fee go through the same bounds as their setters
+        let mut lock_tiers = DEFAULT_LOCK_TIERS;
+        lock_tiers[0].multiplier_bps = 12_000;
+        let tiers_and_fees = ParamChange {
+            lock_tiers: Some(lock_tiers),
+            early_exit_penalty_bps: Some(2_500),
+            keeper_fee_bps: Some(MAX_KEEPER_FEE_BPS),
+            ..ParamChange::default()
+        };
+        state.apply_param_change(&tiers_and_fees).unwrap();
+        assert_eq!(state.lock_tiers, lock_tiers);
+        assert_eq!(state.early_exit_penalty_bps, 2_500);
+        assert_eq!(state.penalty_staker_share_bps, DEFAULT_PENALTY_STAKER_SHARE_BPS);
+        assert_eq!(state.keeper_fee_bps, MAX_KEEPER_FEE_BPS);
+        
+        let bad_fee = ParamChange { keeper_fee_bps: Some(MAX_KEEPER_FEE_BPS + 1), ..ParamChange::default() };
+        assert!(state.apply_param_change(&bad_fee).is_err());
+        let bad_penalty = ParamChange { penalty_staker_share_bps: Some(10_001), ..ParamChange::default() };
+        assert!(state.apply_param_change(&bad_penalty).is_err());
+        lock_tiers[1].multiplier_bps = 11_000;
+        let bad_tiers = ParamChange { lock_tiers: Some(lock_tiers), ..ParamChange::default() };
+        assert!(state.apply_param_change(&bad_tiers).is_err());
+        
+        // Invalid changes leave the state untouched
+        let too_long = ParamChange { param_timelock: Some(MAX_PARAM_TIMELOCK + 1), ..change };
+        assert!(state.apply_param_change(&too_long).is_err());
+        let bad_stake = ParamChange { min_stake_amount: Some(0), lock_period: Some(MIN_LOCK_PERIOD), ..ParamChange::default() };
+        assert!(state.apply_param_change(&bad_stake).is_err());
+        assert_eq!(state.lock_period, MIN_LOCK_PERIOD * 2);
+        
+        assert_eq!(state.next_param_change_id().unwrap(), 0);
+        assert_eq!(state.next_param_change_id().unwrap(), 1);
+        
+        let pending = PendingParamChange {
+            global_staking_state: Pubkey::new_unique(),
+            id: 0,
+            proposer: state.authority,
+            change,
+            eta: 1_000,
+            bump: 255,
+        };
+        assert!(!pending.is_ready(999));
+        assert!(pending.is_ready(1_000));
+    }
+    
+    #[test]
+    fn multisig_members_and_threshold() {
+        let members = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        assert!(Multisig::validate_members(&members, 2).is_ok());
+        assert!(Multisig::validate_members(&members, 0).is_err());
+        assert!(Multisig::validate_members(&members, 4).is_err());
+        assert!(Multisig::validate_members(&[], 1).is_err());
+        assert!(Multisig::validate_members(&[members[0], members[0]], 1).is_err());
+        assert!(Multisig::validate_members(&[Pubkey::default()], 1).is_err());
+        assert!(Multisig::validate_members(&[Pubkey::new_unique(); MAX_MULTISIG_MEMBERS + 1], 1).is_err());
+        
+        let mut multisig = Multisig {
+            global_staking_state: Pubkey::new_unique(),
+            members: members.to_vec(),
+            threshold: 2,
+            proposal_count: 0,
+            member_set_version: 0,
+            bump: 255,
+        };
+        assert_eq!(multisig.member_index(&members[2]).unwrap(), 2);
+        assert!(multisig.member_index(&Pubkey::new_unique()).is_err());
+        
+        // Every change bumps the version; invalid ones leave the multisig untouched
+        let new_member = Pubkey::new_unique();
+        multisig.add_member(new_member).unwrap();
+        assert!(multisig.add_member(new_member).is_err());
+        multisig.remove_member(members[0]).unwrap();
+        assert_eq!(multisig.member_index(&new_member).unwrap(), 2);
+        multisig.change_threshold(3).unwrap();
+        assert!(multisig.change_threshold(4).is_err());
+        assert!(multisig.remove_member(members[1]).is_err());
+        assert!(multisig.remove_member(members[0]).is_err());
+        assert_eq!(multisig.members, vec![members[1], members[2], new_member]);
+        assert_eq!(multisig.threshold, 3);
+        assert_eq!(multisig.member_set_version, 3);
+    }
+    
+    #[test]
+    fn multisig_actions_fit_proposal_space() {
+        let actions = [
+            MultisigAction::UpdateStakingParams(ParamChange {
+                min_stake_amount: Some(1),
+                max_stake_amount: Some(1),
+                lock_period: Some(1),
+                unbonding_period: Some(1),
+                param_timelock: Some(1),
+                lock_tiers: Some(DEFAULT_LOCK_TIERS),
+                early_exit_penalty_bps: Some(1),
+                penalty_staker_share_bps: Some(1),
+                keeper_fee_bps: Some(1),
+            }),
+            MultisigAction::SetRateManager {
+                rate_manager: Pubkey::new_unique(),
+                min_reward_rate: 1,
+                max_reward_rate: 2,
+            },
+            MultisigAction::UpdateLockTiers(DEFAULT_LOCK_TIERS),
+            MultisigAction::CancelParamChange(u64::MAX),
+        ];
+        for action in actions {
+            assert!(action.try_to_vec().unwrap().len() <= MultisigAction::LEN);
+        }
+    }
+    
+    #[test]
+    fn multisig_proposal_needs_threshold_approvals() {
+        let mut proposal = MultisigProposal {
+            multisig: Pubkey::new_unique(),
+            id: 0,
+            proposer: Pubkey::new_unique(),
+            action: MultisigAction::AcceptAuthority,
+            member_set_version: 0,
+            approvals: 0,
+            executed: false,
+            bump: 255,
+        };
+        
+        proposal.approve(0).unwrap();
+        assert!(!proposal.is_approved(2));
+        assert!(proposal.approve(0).is_err());
+        
+        proposal.approve(2).unwrap();
+        assert_eq!(proposal.approval_count(), 2);
+        assert!(proposal.is_approved(2));
+        
+        proposal.executed = true;
+        assert!(proposal.approve(1).is_err());
+    }
+    
+    #[test]
+    fn rate_manager_changes_the_running_period() {
+        let mut state = global_state();
+        state.period_finish = 0;
+        state.min_managed_reward_rate = 5;
+        state.max_managed_reward_rate = 20;
+        state.notify_reward_amount(1_000, 100, 0).unwrap();
+        assert_eq!(state.reward_rate, 10);
+        
+        // Halfway through, 500 is owed and 500 is left for the rest of the period
+        state.update_rewards(&no_schedule(), 50).unwrap();
+        assert!(state.set_period_reward_rate(25, u64::MAX, 50).is_err());
+        assert_eq!(
+            state.set_period_reward_rate(15, 1_000, 50).unwrap_err(),
+            error!(StakingError::InsufficientRewardFunds)
+        );
+        
+        state.set_period_reward_rate(15, 1_250, 50).unwrap();
+        assert_eq!(state.period_finish, 100);
+        
+        // The new rate accrues for the rest of the period
+        state.update_rewards(&no_schedule(), 100).unwrap();
+        assert_eq!(state.rewards_owed, 500 + 750);
+        assert_eq!(state.accumulated_rewards_per_token, math::amount_per_token(1_250, 1_000).unwrap());
+        
+        // Nothing to change once the period is over
+        assert_eq!(
+            state.set_period_reward_rate(10, u64::MAX, 100).unwrap_err(),
+            error!(StakingError::RewardPeriodInactive)
+        );
+    }
+    
+    #[test]
+    fn schedule_validation() {
+        let breakpoints = [
+            EmissionBreakpoint { start_time: 100, reward_rate: 40 },
+            EmissionBreakpoint { start_time: 200, reward_rate: 0 },
+        ];
+        assert!(EmissionSchedule::validate_breakpoints(&breakpoints).is_ok());
+        assert!(EmissionSchedule::validate_breakpoints(&[]).is_ok());
+        
+        // Schedules must end at rate zero
+        assert!(EmissionSchedule::validate_breakpoints(&breakpoints[..1]).is_err());
+        
+        let unordered = [breakpoints[0], EmissionBreakpoint { start_time: 100, reward_rate: 0 }];
+        assert!(EmissionSchedule::validate_breakpoints(&unordered).is_err());
+        
+        let too_fast = [
+            EmissionBreakpoint { start_time: 0, reward_rate: MAX_REWARD_RATE + 1 },
+            EmissionBreakpoint { start_time: 1, reward_rate: 0 },
+        ];
+        assert!(EmissionSchedule::validate_breakpoints(&too_fast).is_err());
+        
+        // Funding covers what is left of the schedule from initialization onwards
+        let schedule = EmissionSchedule {
+            global_staking_state: Pubkey::new_unique(),
+            bump: 255,
+            breakpoints: breakpoints.to_vec(),
+        };
+        assert_eq!(schedule.remaining_emission(0), Some(100 * 40));
+        assert_eq!(schedule.remaining_emission(150), Some(50 * 40));
+        assert_eq!(schedule.remaining_emission(200), Some(0));
+        assert_eq!(no_schedule().remaining_emission(0), Some(0));
+    }
 }
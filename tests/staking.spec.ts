@@ -17,10 +17,31 @@ describe("leash-staking", () => {
   const leashMint = Keypair.generate();
   const xleashMint = Keypair.generate();
   const treasury = Keypair.generate();
+  const multisigMembers = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  
+  // Pool identifier (several pools may stake the same mint)
+  const poolId = new anchor.BN(0);
+  
+  // Parameter change that leaves every parameter as is
+  const noParamChange = {
+    minStakeAmount: null,
+    maxStakeAmount: null,
+    lockPeriod: null,
+    unbondingPeriod: null,
+    paramTimelock: null,
+    lockTiers: null,
+    earlyExitPenaltyBps: null,
+    penaltyStakerShareBps: null,
+    keeperFeeBps: null,
+  };
   
   // PDAs
   let globalStakingState: PublicKey;
   let userStakingPosition: PublicKey;
+  let vaultAuthority: PublicKey;
+  let stakeVault: PublicKey;
+  let rewardVault: PublicKey;
+  let emissionSchedule: PublicKey;
   
   before(async () => {
     // Airdrop SOL to test accounts
@@ -32,7 +53,31 @@ describe("leash-staking", () => {
     
     // Derive PDAs
     [globalStakingState] = PublicKey.findProgramAddressSync(
-      [Buffer.from("global_staking_state")],
+      [
+        Buffer.from("global_staking_state"),
+        leashMint.publicKey.toBuffer(),
+        poolId.toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    );
+    
+    [vaultAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_authority"), globalStakingState.toBuffer()],
+      program.programId
+    );
+    
+    [stakeVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_vault"), globalStakingState.toBuffer()],
+      program.programId
+    );
+    
+    [rewardVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_vault"), globalStakingState.toBuffer()],
+      program.programId
+    );
+    
+    [emissionSchedule] = PublicKey.findProgramAddressSync(
+      [Buffer.from("emission_schedule"), globalStakingState.toBuffer()],
       program.programId
     );
     
@@ -47,19 +92,24 @@ describe("leash-staking", () => {
   });
 
   it("Initializes the staking program", async () => {
-    const rewardRate = new anchor.BN(1000); // 1000 tokens per second per staked token
+    const rewardRate = new anchor.BN(1000); // 1000 base units per second across the pool
     const minStakeAmount = new anchor.BN(1000000); // 1 LEASH (assuming 6 decimals)
     const maxStakeAmount = new anchor.BN(1000000000000); // 1,000,000 LEASH
     const lockPeriod = new anchor.BN(86400); // 1 day
     
     try {
       await program.methods
-        .initialize(rewardRate, minStakeAmount, maxStakeAmount, lockPeriod)
+        .initialize(poolId, rewardRate, minStakeAmount, maxStakeAmount, lockPeriod, []) // No emission schedule
         .accounts({
           globalStakingState,
+          emissionSchedule,
           leashMint: leashMint.publicKey,
           xleashMint: xleashMint.publicKey,
           treasury: treasury.publicKey,
+          vaultAuthority,
+          stakeVault,
+          rewardVault,
+          funderLeashAccount: null, // Nothing to fund without a schedule
           authority: authority.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -71,6 +121,8 @@ describe("leash-staking", () => {
       // Verify the global staking state was created
       const globalState = await program.account.globalStakingState.fetch(globalStakingState);
       assert.equal(globalState.authority.toString(), authority.publicKey.toString());
+      assert.equal(globalState.poolId.toString(), poolId.toString());
+      assert.equal(globalState.leashMint.toString(), leashMint.publicKey.toString());
       assert.equal(globalState.rewardRate.toString(), rewardRate.toString());
       assert.equal(globalState.minStakeAmount.toString(), minStakeAmount.toString());
       assert.equal(globalState.maxStakeAmount.toString(), maxStakeAmount.toString());
@@ -89,16 +141,18 @@ describe("leash-staking", () => {
     const stakeAmount = new anchor.BN(10000000); // 10 LEASH
     
     try {
-      await program.methods
-        .stake(stakeAmount)
+      const signature = await program.methods
+        .stake(stakeAmount, null)
         .accounts({
           globalStakingState,
+          emissionSchedule,
           userStakingPosition,
           userLeashAccount: user.publicKey, // This would be the actual token account
           userXleashAccount: user.publicKey, // This would be the actual token account
           leashMint: leashMint.publicKey,
           xleashMint: xleashMint.publicKey,
-          treasury: treasury.publicKey,
+          stakeVault,
+          vaultAuthority,
           user: user.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -106,15 +160,32 @@ describe("leash-staking", () => {
           rent: SYSVAR_RENT_PUBKEY,
         })
         .signers([user])
-        .rpc();
+        .rpc({ commitment: "confirmed" });
       
       // Verify the stake was recorded
       const userPosition = await program.account.userStakingPosition.fetch(userStakingPosition);
       assert.equal(userPosition.owner.toString(), user.publicKey.toString());
       assert.equal(userPosition.stakedAmount.toString(), stakeAmount.toString());
+      assert.equal(userPosition.isLocked, true);
+      assert.isAbove(userPosition.lockEndTime.toNumber(), 0);
       
       const globalState = await program.account.globalStakingState.fetch(globalStakingState);
       assert.equal(globalState.totalStaked.toString(), stakeAmount.toString());
+      assert.equal(globalState.totalWeightedStake.toString(), stakeAmount.toString());
+      
+      // Verify the indexer-facing event
+      const transaction = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = [...eventParser.parseLogs(transaction.meta.logMessages)];
+      const staked = events.find((event) => event.name === "Staked");
+      assert.isDefined(staked);
+      assert.equal(staked.data.user.toString(), user.publicKey.toString());
+      assert.equal(staked.data.globalStakingState.toString(), globalStakingState.toString());
+      assert.equal(staked.data.amount.toString(), stakeAmount.toString());
+      assert.equal(staked.data.totalStaked.toString(), stakeAmount.toString());
       
       console.log("✅ LEASH tokens staked successfully");
     } catch (error) {
@@ -129,9 +200,11 @@ describe("leash-staking", () => {
         .getStakingStats()
         .accounts({
           globalStakingState,
+          emissionSchedule,
         })
         .view();
       
+      assert.equal(stats.poolId.toString(), poolId.toString());
       assert.equal(stats.totalStaked.toString(), "10000000");
       assert.equal(stats.isPaused, false);
       
@@ -143,4 +216,657 @@ describe("leash-staking", () => {
       throw error;
     }
   });
+
+  it("Updates staking parameters", async () => {
+    const newMinStakeAmount = new anchor.BN(2000000); // 2 LEASH
+    
+    try {
+      await program.methods
+        .updateStakingParams({ ...noParamChange, minStakeAmount: newMinStakeAmount })
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.minStakeAmount.toString(), newMinStakeAmount.toString());
+      assert.equal(globalState.rewardRate.toString(), "1000");
+      assert.equal(globalState.lockPeriod.toString(), "86400");
+      assert.equal(globalState.unbondingPeriod.toString(), "604800");
+      
+      console.log("✅ Staking parameters updated successfully");
+    } catch (error) {
+      console.error("❌ Failed to update staking parameters:", error);
+      throw error;
+    }
+  });
+
+  it("Rejects out-of-bounds staking parameters", async () => {
+    try {
+      await program.methods
+        .updateStakingParams({ ...noParamChange, lockPeriod: new anchor.BN(3600) }) // Below the 1 day minimum
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("Expected update to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidStakeAmount");
+      console.log("✅ Out-of-bounds parameters rejected");
+    }
+  });
+
+  it("Rejects unbonding periods above the maximum", async () => {
+    try {
+      await program.methods
+        .updateStakingParams({ ...noParamChange, unbondingPeriod: new anchor.BN(2592001) }) // Above the 30 day maximum
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("Expected update to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidUnbondingPeriod");
+      console.log("✅ Out-of-bounds unbonding period rejected");
+    }
+  });
+
+  it("Rejects early-exit penalties above 100%", async () => {
+    try {
+      await program.methods
+        .updatePenaltyParams(10001, 5000)
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("Expected update to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidPenaltyParams");
+      console.log("✅ Out-of-bounds penalty rejected");
+    }
+  });
+
+  it("Rejects keeper fees above the maximum", async () => {
+    try {
+      await program.methods
+        .setKeeperFee(101) // Above the 1% maximum
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("Expected update to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidKeeperFee");
+      console.log("✅ Out-of-bounds keeper fee rejected");
+    }
+  });
+
+  it("Rejects reward periods with zero duration", async () => {
+    try {
+      await program.methods
+        .notifyRewardAmount(new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          globalStakingState,
+          emissionSchedule,
+          rewardVault,
+          funderLeashAccount: authority.publicKey, // This would be the actual token account
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("Expected notify to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidRewardDuration");
+      console.log("✅ Zero-length reward period rejected");
+    }
+  });
+
+  it("Rejects lock tiers with decreasing multipliers", async () => {
+    const lockTiers = [
+      { duration: new anchor.BN(2592000), multiplierBps: 15000 },
+      { duration: new anchor.BN(15552000), multiplierBps: 11000 },
+      { duration: new anchor.BN(31536000), multiplierBps: 20000 },
+      { duration: new anchor.BN(126144000), multiplierBps: 40000 },
+    ];
+    
+    try {
+      await program.methods
+        .updateLockTiers(lockTiers)
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("Expected update to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidLockTier");
+      console.log("✅ Invalid lock tiers rejected");
+    }
+  });
+
+  it("Pauses new stakes while leaving unstake available", async () => {
+    const PAUSE_STAKE = 1 << 0;
+    const PAUSE_UNSTAKE = 1 << 1;
+    
+    try {
+      await program.methods
+        .emergencyPause(PAUSE_STAKE, true, 1)
+        .accounts({
+          globalStakingState,
+          signer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      let globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.pauseFlags & PAUSE_STAKE, PAUSE_STAKE);
+      assert.equal(globalState.pauseFlags & PAUSE_UNSTAKE, 0);
+      assert.equal(globalState.isPaused, true);
+      
+      await program.methods
+        .emergencyPause(PAUSE_STAKE, false, 1)
+        .accounts({
+          globalStakingState,
+          signer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.pauseFlags, 0);
+      assert.equal(globalState.isPaused, false);
+      
+      console.log("✅ Granular pause toggled successfully");
+    } catch (error) {
+      console.error("❌ Failed to toggle pause flags:", error);
+      throw error;
+    }
+  });
+
+  it("Rejects pause toggles from non-guardian keys", async () => {
+    try {
+      await program.methods
+        .emergencyPause(1, true, 1)
+        .accounts({
+          globalStakingState,
+          signer: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Expected pause to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "Unauthorized");
+      console.log("✅ Unauthorized pause rejected");
+    }
+  });
+
+  it("Requires the proposed authority to accept a transfer", async () => {
+    try {
+      await program.methods
+        .proposeAuthority(user.publicKey)
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      let globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.authority.toString(), authority.publicKey.toString());
+      assert.equal(globalState.pendingAuthority.toString(), user.publicKey.toString());
+      
+      try {
+        await program.methods
+          .acceptAuthority()
+          .accounts({
+            globalStakingState,
+            newAuthority: treasury.publicKey,
+          })
+          .signers([treasury])
+          .rpc();
+        assert.fail("Expected accept to be rejected");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+      
+      // Cancel the transfer so later tests keep the original authority
+      await program.methods
+        .proposeAuthority(PublicKey.default)
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.pendingAuthority.toString(), PublicKey.default.toString());
+      
+      console.log("✅ Authority transfer proposed, rejected and cancelled");
+    } catch (error) {
+      console.error("❌ Failed to exercise authority transfer:", error);
+      throw error;
+    }
+  });
+
+  it("Lets the rate manager set the reward rate only within bounds", async () => {
+    try {
+      await program.methods
+        .setRateManager(user.publicKey, new anchor.BN(1000), new anchor.BN(3000))
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.rateManager.toString(), user.publicKey.toString());
+      
+      try {
+        await program.methods
+          .setRewardRate(new anchor.BN(5000)) // Above the managed maximum
+          .accounts({
+            globalStakingState,
+            emissionSchedule,
+            rewardVault,
+            rateManager: user.publicKey,
+          })
+          .signers([user])
+          .rpc();
+        assert.fail("Expected rate change to be rejected");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidRewardRate");
+      }
+      
+      // Within bounds, but there is no running reward period to change
+      try {
+        await program.methods
+          .setRewardRate(new anchor.BN(2500))
+          .accounts({
+            globalStakingState,
+            emissionSchedule,
+            rewardVault,
+            rateManager: user.publicKey,
+          })
+          .signers([user])
+          .rpc();
+        assert.fail("Expected rate change to be rejected");
+      } catch (error) {
+        assert.include(error.toString(), "RewardPeriodInactive");
+      }
+      
+      console.log("✅ Rate manager bounded reward rate changes");
+    } catch (error) {
+      console.error("❌ Failed to exercise the rate manager role:", error);
+      throw error;
+    }
+  });
+
+  it("Rejects reward rate changes from keys without the rate manager role", async () => {
+    try {
+      await program.methods
+        .setRewardRate(new anchor.BN(2000))
+        .accounts({
+          globalStakingState,
+          emissionSchedule,
+          rewardVault,
+          rateManager: treasury.publicKey,
+        })
+        .signers([treasury])
+        .rpc();
+      assert.fail("Expected rate change to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "Unauthorized");
+      console.log("✅ Unauthorized rate change rejected");
+    }
+  });
+
+  it("Queues and cancels a timelocked parameter change", async () => {
+    const [pendingParamChange] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("pending_param_change"),
+        globalStakingState.toBuffer(),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    );
+    
+    try {
+      await program.methods
+        .queueParamChange({ ...noParamChange, lockPeriod: new anchor.BN(172800) })
+        .accounts({
+          globalStakingState,
+          pendingParamChange,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const pending = await program.account.pendingParamChange.fetch(pendingParamChange);
+      assert.equal(pending.id.toString(), "0");
+      assert.equal(pending.change.lockPeriod.toString(), "172800");
+      
+      await program.methods
+        .cancelParamChange()
+        .accounts({
+          globalStakingState,
+          pendingParamChange,
+          proposer: authority.publicKey,
+          signer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const closed = await provider.connection.getAccountInfo(pendingParamChange);
+      assert.isNull(closed);
+      
+      const globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.lockPeriod.toString(), "86400");
+      assert.equal(globalState.paramChangeNonce.toString(), "1");
+      
+      console.log("✅ Timelocked parameter change queued and cancelled");
+    } catch (error) {
+      console.error("❌ Failed to queue and cancel a parameter change:", error);
+      throw error;
+    }
+  });
+
+  it("Pauses through a 2-of-3 multisig guardian", async () => {
+    const members = multisigMembers;
+    const airdrop = await provider.connection.requestAirdrop(members[0].publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(airdrop);
+    
+    const [multisig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), globalStakingState.toBuffer()],
+      program.programId
+    );
+    const [proposal] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig_proposal"),
+        multisig.toBuffer(),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    );
+    const executeAccounts = {
+      globalStakingState,
+      emissionSchedule,
+      multisig,
+      proposal,
+      rewardVault: null,
+      pendingParamChange: null,
+      cancelledParamChange: null,
+      proposer: null,
+      rewardMint: null,
+      vaultAuthority: null,
+      streamVault: null,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: null,
+      rent: null,
+    };
+    
+    try {
+      await program.methods
+        .createMultisig(members.map((member) => member.publicKey), 2)
+        .accounts({
+          globalStakingState,
+          multisig,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      await program.methods
+        .setGuardian(multisig)
+        .accounts({
+          globalStakingState,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      await program.methods
+        .createMultisigProposal({ setPaused: { operations: 1, pause: true, reasonCode: 7 } })
+        .accounts({
+          multisig,
+          proposal,
+          member: members[0].publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([members[0]])
+        .rpc();
+      
+      try {
+        await program.methods.executeMultisigProposal().accounts(executeAccounts).rpc();
+        assert.fail("Expected execution to be rejected");
+      } catch (error) {
+        assert.include(error.toString(), "ProposalNotApproved");
+      }
+      
+      await program.methods
+        .approveMultisigProposal()
+        .accounts({
+          multisig,
+          proposal,
+          member: members[1].publicKey,
+        })
+        .signers([members[1]])
+        .rpc();
+      
+      await program.methods.executeMultisigProposal().accounts(executeAccounts).rpc();
+      
+      let globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.pauseFlags & 1, 1);
+      
+      // Resume with the authority so later tests are unaffected
+      await program.methods
+        .emergencyPause(1, false, 7)
+        .accounts({
+          globalStakingState,
+          signer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      globalState = await program.account.globalStakingState.fetch(globalStakingState);
+      assert.equal(globalState.pauseFlags, 0);
+      
+      console.log("✅ Multisig proposal approved and executed");
+    } catch (error) {
+      console.error("❌ Failed to pause through the multisig:", error);
+      throw error;
+    }
+  });
+
+  it("Adds a multisig member and invalidates older proposals", async () => {
+    const members = multisigMembers;
+    const newMember = Keypair.generate();
+    
+    const [multisig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), globalStakingState.toBuffer()],
+      program.programId
+    );
+    const proposalAddress = (id: number) => PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig_proposal"),
+        multisig.toBuffer(),
+        new anchor.BN(id).toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    )[0];
+    const executeAccounts = (proposal: PublicKey) => ({
+      globalStakingState,
+      emissionSchedule,
+      multisig,
+      proposal,
+      rewardVault: null,
+      pendingParamChange: null,
+      cancelledParamChange: null,
+      proposer: null,
+      rewardMint: null,
+      vaultAuthority: null,
+      streamVault: null,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: null,
+      rent: null,
+    });
+    
+    try {
+      // Proposal 1 adds a member; proposal 2 is left open across the change
+      for (const action of [{ addMember: [newMember.publicKey] }, { setPaused: { operations: 1, pause: true, reasonCode: 8 } }]) {
+        const { proposalCount } = await program.account.multisig.fetch(multisig);
+        await program.methods
+          .createMultisigProposal(action)
+          .accounts({
+            multisig,
+            proposal: proposalAddress(proposalCount.toNumber()),
+            member: members[0].publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([members[0]])
+          .rpc();
+      }
+      
+      await program.methods
+        .approveMultisigProposal()
+        .accounts({
+          multisig,
+          proposal: proposalAddress(1),
+          member: members[1].publicKey,
+        })
+        .signers([members[1]])
+        .rpc();
+      await program.methods.executeMultisigProposal().accounts(executeAccounts(proposalAddress(1))).rpc();
+      
+      const multisigState = await program.account.multisig.fetch(multisig);
+      assert.equal(multisigState.members.length, 4);
+      assert.equal(multisigState.memberSetVersion, 1);
+      
+      try {
+        await program.methods
+          .approveMultisigProposal()
+          .accounts({
+            multisig,
+            proposal: proposalAddress(2),
+            member: members[1].publicKey,
+          })
+          .signers([members[1]])
+          .rpc();
+        assert.fail("Expected the stale proposal to be rejected");
+      } catch (error) {
+        assert.include(error.toString(), "StaleMultisigProposal");
+      }
+      
+      console.log("✅ Multisig member added through a proposal");
+    } catch (error) {
+      console.error("❌ Failed to change multisig members:", error);
+      throw error;
+    }
+  });
+
+  it("Cancels a queued parameter change through the multisig guardian", async () => {
+    const members = multisigMembers;
+    
+    const [multisig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), globalStakingState.toBuffer()],
+      program.programId
+    );
+    const [pendingParamChange] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("pending_param_change"),
+        globalStakingState.toBuffer(),
+        new anchor.BN(1).toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    );
+    
+    try {
+      await program.methods
+        .queueParamChange({ ...noParamChange, unbondingPeriod: new anchor.BN(86400) })
+        .accounts({
+          globalStakingState,
+          pendingParamChange,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const { proposalCount } = await program.account.multisig.fetch(multisig);
+      const [proposal] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("multisig_proposal"),
+          multisig.toBuffer(),
+          proposalCount.toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+      
+      await program.methods
+        .createMultisigProposal({ cancelParamChange: [new anchor.BN(1)] })
+        .accounts({
+          multisig,
+          proposal,
+          member: members[0].publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([members[0]])
+        .rpc();
+      await program.methods
+        .approveMultisigProposal()
+        .accounts({
+          multisig,
+          proposal,
+          member: members[1].publicKey,
+        })
+        .signers([members[1]])
+        .rpc();
+      
+      await program.methods
+        .executeMultisigProposal()
+        .accounts({
+          globalStakingState,
+          emissionSchedule,
+          multisig,
+          proposal,
+          rewardVault: null,
+          pendingParamChange: null,
+          cancelledParamChange: pendingParamChange,
+          proposer: authority.publicKey,
+          rewardMint: null,
+          vaultAuthority: null,
+          streamVault: null,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: null,
+          rent: null,
+        })
+        .rpc();
+      
+      const closed = await provider.connection.getAccountInfo(pendingParamChange);
+      assert.isNull(closed);
+      
+      console.log("✅ Queued parameter change cancelled by the multisig guardian");
+    } catch (error) {
+      console.error("❌ Failed to cancel through the multisig:", error);
+      throw error;
+    }
+  });
 });